@@ -1,9 +1,25 @@
 use crate::config_error::ConfigError;
+use crate::config_field_error::ConfigFieldError;
+use crate::field_info::FieldInfo;
+use crate::field_path::FieldPath;
+use crate::load_context::LoadContext;
+use crate::load_outcome::LoadOutcome;
+use crate::load_report::LoadReport;
+use crate::source::Source;
+#[cfg(feature = "json")]
+use crate::sources::Json;
+#[cfg(feature = "toml")]
+use crate::sources::Toml;
+#[cfg(feature = "yaml")]
+use crate::sources::Yaml;
+use crate::sources::{Args, Credentials, Directory, DotEnv, Env, Layered};
+use std::cell::RefCell;
+use std::path::Path;
 
 /// A trait for types that can be loaded from environment variables.
 ///
 /// This trait is typically implemented automatically using the `#[derive(Config)]` macro.
-/// The generated implementation reads environment variables based on `#[env]` attributes,
+/// The generated implementation reads values from a [`Source`] based on `#[env]` attributes,
 /// applies default values from `#[default]` attributes, and handles nested configurations
 /// marked with `#[config]`.
 ///
@@ -60,7 +76,8 @@ pub trait Config {
     ///
     /// Reads all required environment variables, applies defaults, validates fields,
     /// and returns either a fully constructed configuration or a collection of all
-    /// errors encountered.
+    /// errors encountered. Fields with `#[credential]` attributes are also read from systemd
+    /// credentials, see [`Credentials::from_env`].
     ///
     /// # Errors
     ///
@@ -68,6 +85,497 @@ pub trait Config {
     /// vector of all individual field errors, allowing you to diagnose all
     /// configuration problems at once.
     fn load() -> Result<Self, ConfigError>
+    where
+        Self: Sized,
+    {
+        Self::load_from(&env_with_credentials())
+    }
+
+    /// Loads the configuration from the given [`Source`].
+    ///
+    /// Works like [`load`](Config::load), but every `#[env]` name is looked up in `source`
+    /// instead of the process environment. Nested configurations marked with `#[config]`
+    /// are loaded from the same source.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError`] if any field fails to load. Lookup failures reported by the
+    /// source are returned as [`ConfigFieldError::Other`].
+    /// Errors reported by [`Source::errors`] (e.g. syntax errors in a `.env` file) are
+    /// included before the field errors, and fail the loading even if every field loaded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tryphon::{Config, EnvOverrides};
+    ///
+    /// #[derive(Config)]
+    /// struct AppConfig {
+    ///     #[env("PORT")]
+    ///     port: u16,
+    /// }
+    ///
+    /// let mut overrides = EnvOverrides::init();
+    /// overrides.set("PORT", "9090");
+    ///
+    /// let config = AppConfig::load_from(&overrides).unwrap();
+    /// assert_eq!(config.port, 9090);
+    /// ```
+    fn load_from(source: &impl Source) -> Result<Self, ConfigError>
+    where
+        Self: Sized,
+    {
+        with_source_errors(
+            source.errors(),
+            Self::load_fields(&LoadContext::new(source).declaring::<Self>()),
+        )
+    }
+
+    /// Loads the configuration from environment variables, with every `#[env]` name prefixed
+    /// with `prefix`.
+    ///
+    /// The prefix also applies to nested configurations, on top of their own
+    /// `#[config(prefix = "...")]` prefixes. This lets one configuration struct be loaded
+    /// several times, e.g. for a primary and a replica database.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError`] if any field fails to load. Missing values list the prefixed
+    /// names that were tried.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tryphon::{Config, EnvOverrides};
+    ///
+    /// #[derive(Config)]
+    /// struct DbConfig {
+    ///     #[env("DB_HOST")]
+    ///     host: String,
+    /// }
+    ///
+    /// let mut overrides = EnvOverrides::init();
+    /// overrides.set("REPLICA_DB_HOST", "replica.internal");
+    ///
+    /// let config = DbConfig::load_with_prefix("REPLICA_").unwrap();
+    /// assert_eq!(config.host, "replica.internal");
+    /// ```
+    fn load_with_prefix(prefix: &str) -> Result<Self, ConfigError>
+    where
+        Self: Sized,
+    {
+        Self::load_from_with_prefix(&env_with_credentials(), prefix)
+    }
+
+    /// Loads the configuration from the given [`Source`], with every `#[env]` name prefixed
+    /// with `prefix`.
+    ///
+    /// Works like [`load_from`](Config::load_from), see
+    /// [`load_with_prefix`](Config::load_with_prefix) for how the prefix is applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError`] if any field fails to load, exactly like
+    /// [`load_from`](Config::load_from).
+    fn load_from_with_prefix(source: &impl Source, prefix: &str) -> Result<Self, ConfigError>
+    where
+        Self: Sized,
+    {
+        with_source_errors(
+            source.errors(),
+            Self::load_fields(
+                &LoadContext::new(source)
+                    .with_prefix(prefix)
+                    .declaring::<Self>(),
+            ),
+        )
+    }
+
+    /// Loads the configuration from environment variables, together with a [`LoadReport`]
+    /// recording where the value of every field came from.
+    ///
+    /// Works like [`load`](Config::load). The report tells, for every field, which environment
+    /// variable (or other key) provided the value, whether the `#[default]` value was used,
+    /// and which of the `#[env]` names were tried and not set.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError`] if any field fails to load, exactly like [`load`](Config::load).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tryphon::{Config, EnvOverrides};
+    ///
+    /// #[derive(Config)]
+    /// struct AppConfig {
+    ///     #[env("PORT")]
+    ///     #[default(8080)]
+    ///     port: u16,
+    /// }
+    ///
+    /// let _overrides = EnvOverrides::init();
+    ///
+    /// let (config, report) = AppConfig::load_with_report().unwrap();
+    ///
+    /// assert_eq!(config.port, 8080);
+    /// assert_eq!(report.to_string(), "port: default, missing: PORT\n");
+    /// ```
+    fn load_with_report() -> Result<(Self, LoadReport), ConfigError>
+    where
+        Self: Sized,
+    {
+        Self::load_from_with_report(&env_with_credentials())
+    }
+
+    /// Loads the configuration from the given [`Source`], together with a [`LoadReport`]
+    /// recording where the value of every field came from.
+    ///
+    /// Works like [`load_from`](Config::load_from), see
+    /// [`load_with_report`](Config::load_with_report) for the contents of the report.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError`] if any field fails to load, exactly like
+    /// [`load_from`](Config::load_from).
+    fn load_from_with_report(source: &impl Source) -> Result<(Self, LoadReport), ConfigError>
+    where
+        Self: Sized,
+    {
+        let report = RefCell::new(LoadReport::default());
+        let result =
+            Self::load_fields(&LoadContext::with_report(source, &report).declaring::<Self>());
+
+        with_source_errors(source.errors(), result).map(|config| (config, report.into_inner()))
+    }
+
+    /// Loads the configuration from the process environment, with `.env` files layered
+    /// underneath it.
+    ///
+    /// Variables set in the environment take precedence; variables only defined in the
+    /// `.env` files are used as a fallback. Later files override earlier ones, see
+    /// [`DotEnv::from_paths`]. The process environment is never modified.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError`] if any field fails to load, or if any of the files can't be
+    /// read or contains syntax errors. File errors are reported as
+    /// [`ConfigFieldError::SourceError`] with the
+    /// file name and line number.
+    ///
+    /// # Examples
+    ///
+    /// ```rust no_run
+    /// use tryphon::Config;
+    ///
+    /// #[derive(Config)]
+    /// struct AppConfig {
+    ///     #[env("DATABASE_URL")]
+    ///     database_url: String,
+    /// }
+    ///
+    /// let config = AppConfig::load_with_dotenv([".env", ".env.local"]).unwrap();
+    /// ```
+    fn load_with_dotenv<P: AsRef<Path>>(
+        paths: impl IntoIterator<Item = P>,
+    ) -> Result<Self, ConfigError>
+    where
+        Self: Sized,
+    {
+        Self::load_from(
+            &Layered::new()
+                .layer(DotEnv::from_paths(paths))
+                .layer(env_with_credentials()),
+        )
+    }
+
+    /// Loads the configuration from the process environment, with a directory of files layered
+    /// underneath it.
+    ///
+    /// Every field is first looked up by its `#[env]` names in the process environment; if none
+    /// is set, the value is read from the file with the same name in the directory, see
+    /// [`Directory`]. This suits ConfigMaps and Secrets mounted as volumes in Kubernetes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError`] if any field fails to load, or if the directory doesn't exist.
+    /// Files that can't be read, or are larger than [`DEFAULT_SIZE_LIMIT`](crate::sources::DEFAULT_SIZE_LIMIT),
+    /// are reported as [`ConfigFieldError::Other`] for the field.
+    ///
+    /// # Examples
+    ///
+    /// ```rust no_run
+    /// use tryphon::Config;
+    ///
+    /// #[derive(Config)]
+    /// struct AppConfig {
+    ///     #[env("DATABASE_URL")]
+    ///     database_url: String,
+    /// }
+    ///
+    /// // Reads /etc/config/DATABASE_URL unless DATABASE_URL is set
+    /// let config = AppConfig::load_with_directory("/etc/config").unwrap();
+    /// ```
+    fn load_with_directory(path: impl AsRef<Path>) -> Result<Self, ConfigError>
+    where
+        Self: Sized,
+    {
+        Self::load_from(
+            &Layered::new()
+                .layer(Directory::from_path(path))
+                .layer(env_with_credentials()),
+        )
+    }
+
+    /// Loads the configuration from the process environment, with command-line arguments
+    /// layered on top.
+    ///
+    /// Every field can be set with a flag derived from its path, like `--database-port 5432`,
+    /// or with `--set database.port=5432`, see [`Args`]. Arguments take precedence over
+    /// environment variables.
+    ///
+    /// If `--help` or `-h` is passed, nothing is loaded and [`LoadOutcome::Help`] is returned
+    /// with the flags of all fields together with their environment variables and defaults
+    /// (see [`Args::help`]), for the application to print.
+    ///
+    /// Every argument must set a field of the configuration. To mix these flags with flags of
+    /// your own, parse the arguments with your own parser first and pass the rest to
+    /// [`Args::parse`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError`] if any field fails to load, or if the arguments contain unknown
+    /// flags or flags without a value. Argument errors are reported as
+    /// [`ConfigFieldError::SourceError`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust no_run
+    /// use tryphon::{Config, LoadOutcome};
+    ///
+    /// #[derive(Config)]
+    /// struct AppConfig {
+    ///     #[env("PORT")]
+    ///     port: u16,
+    /// }
+    ///
+    /// // my-app --port 9090
+    /// let config = match AppConfig::load_with_args().unwrap() {
+    ///     LoadOutcome::Loaded(config) => config,
+    ///     LoadOutcome::Help(help) => {
+    ///         print!("{}", help);
+    ///         return;
+    ///     }
+    /// };
+    /// ```
+    fn load_with_args() -> Result<LoadOutcome<Self>, ConfigError>
+    where
+        Self: Sized,
+    {
+        let args = Args::from_command_line::<Self>();
+
+        if args.help_requested() {
+            return Ok(LoadOutcome::Help(Args::help::<Self>()));
+        }
+
+        Self::load_from(&Layered::new().layer(env_with_credentials()).layer(args))
+            .map(LoadOutcome::Loaded)
+    }
+
+    /// Loads the configuration from a TOML file, with environment variables layered on top.
+    ///
+    /// Every field is first looked up by its `#[env]` names in the process environment; if
+    /// none is set, the value is read from the file, see [`Toml`] for how fields map to
+    /// tables and keys.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError`] if any field fails to load, or if the file can't be read or
+    /// parsed. File errors are reported as
+    /// [`ConfigFieldError::SourceError`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust no_run
+    /// use tryphon::Config;
+    ///
+    /// #[derive(Config)]
+    /// struct DatabaseConfig {
+    ///     #[env("DB_HOST")]
+    ///     host: String,
+    /// }
+    ///
+    /// #[derive(Config)]
+    /// struct AppConfig {
+    ///     #[env("PORT")]
+    ///     port: u16,
+    ///
+    ///     #[config]
+    ///     database: DatabaseConfig,
+    /// }
+    ///
+    /// // port = 8080
+    /// //
+    /// // [database]
+    /// // host = "localhost"
+    /// let config = AppConfig::load_with_toml("config.toml").unwrap();
+    /// ```
+    #[cfg(feature = "toml")]
+    fn load_with_toml(path: impl AsRef<Path>) -> Result<Self, ConfigError>
+    where
+        Self: Sized,
+    {
+        Self::load_from(
+            &Layered::new()
+                .layer(Toml::from_path(path))
+                .layer(env_with_credentials()),
+        )
+    }
+
+    /// Loads the configuration from a JSON file, with environment variables layered on top.
+    ///
+    /// Works like [`load_with_toml`](Config::load_with_toml), with nested `#[config]` fields
+    /// mapping to JSON objects, see [`Json`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError`] if any field fails to load, or if the file can't be read or
+    /// parsed. File errors are reported as
+    /// [`ConfigFieldError::SourceError`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust no_run
+    /// use tryphon::Config;
+    ///
+    /// #[derive(Config)]
+    /// struct AppConfig {
+    ///     #[env("PORT")]
+    ///     port: u16,
+    /// }
+    ///
+    /// // {"port": 8080}
+    /// let config = AppConfig::load_with_json("config.json").unwrap();
+    /// ```
+    #[cfg(feature = "json")]
+    fn load_with_json(path: impl AsRef<Path>) -> Result<Self, ConfigError>
+    where
+        Self: Sized,
+    {
+        Self::load_from(
+            &Layered::new()
+                .layer(Json::from_path(path))
+                .layer(env_with_credentials()),
+        )
+    }
+
+    /// Loads the configuration from a YAML file, with environment variables layered on top.
+    ///
+    /// Works like [`load_with_toml`](Config::load_with_toml), with nested `#[config]` fields
+    /// mapping to YAML mappings, see [`Yaml`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError`] if any field fails to load, or if the file can't be read or
+    /// parsed. File errors are reported as
+    /// [`ConfigFieldError::SourceError`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust no_run
+    /// use tryphon::Config;
+    ///
+    /// #[derive(Config)]
+    /// struct AppConfig {
+    ///     #[env("PORT")]
+    ///     port: u16,
+    /// }
+    ///
+    /// // port: 8080
+    /// let config = AppConfig::load_with_yaml("config.yaml").unwrap();
+    /// ```
+    #[cfg(feature = "yaml")]
+    fn load_with_yaml(path: impl AsRef<Path>) -> Result<Self, ConfigError>
+    where
+        Self: Sized,
+    {
+        Self::load_from(
+            &Layered::new()
+                .layer(Yaml::from_path(path))
+                .layer(env_with_credentials()),
+        )
+    }
+
+    /// Returns a description of every field loaded from a source, including the fields of
+    /// nested configurations, in declaration order.
+    ///
+    /// For enums, the fields of all variants are listed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tryphon::Config;
+    ///
+    /// #[derive(Config)]
+    /// struct AppConfig {
+    ///     #[env("PORT")]
+    ///     #[default(8080)]
+    ///     port: u16,
+    /// }
+    ///
+    /// let fields = AppConfig::fields();
+    ///
+    /// assert_eq!(fields[0].path.dotted_path(), "port");
+    /// assert_eq!(fields[0].env_vars, vec!["PORT"]);
+    /// assert_eq!(fields[0].default.as_deref(), Some("8080"));
+    /// ```
+    fn fields() -> Vec<FieldInfo>
+    where
+        Self: Sized,
+    {
+        let mut fields = Vec::new();
+        Self::describe_fields(&FieldPath::root(), "", &mut fields);
+        fields
+    }
+
+    /// Appends the descriptions of the fields of the configuration at `path`, whose `#[env]`
+    /// names are prefixed with `prefix`, to `fields`.
+    ///
+    /// This method is generated by `#[derive(Config)]` and used by [`fields`](Config::fields).
+    #[doc(hidden)]
+    fn describe_fields(path: &FieldPath, prefix: &str, fields: &mut Vec<FieldInfo>)
     where
         Self: Sized;
+
+    /// Loads the fields of the configuration using the given [`LoadContext`].
+    ///
+    /// This method is generated by `#[derive(Config)]` and used by [`load_from`](Config::load_from),
+    /// which additionally reports the errors of the source itself. Nested configurations call
+    /// it directly so source errors are reported only once.
+    #[doc(hidden)]
+    fn load_fields(ctx: &LoadContext<'_>) -> Result<Self, ConfigError>
+    where
+        Self: Sized;
+}
+
+/// The process environment with systemd credentials layered over it, the source of
+/// [`Config::load`] and the other `load_with_*` methods. The credentials directory is resolved
+/// once, when the source is created.
+fn env_with_credentials() -> Layered<'static> {
+    Layered::new().layer(Env).layer(Credentials::from_env())
+}
+
+/// Combines errors reported by a source with the result of loading the fields.
+fn with_source_errors<T>(
+    mut field_errors: Vec<ConfigFieldError>,
+    result: Result<T, ConfigError>,
+) -> Result<T, ConfigError> {
+    if field_errors.is_empty() {
+        result
+    } else {
+        if let Err(error) = result {
+            field_errors.extend(error.field_errors);
+        }
+        Err(ConfigError { field_errors })
+    }
 }
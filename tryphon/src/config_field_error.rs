@@ -1,4 +1,5 @@
 use crate::config_error::ConfigError;
+use crate::decode_error::DecodeError;
 
 /// Represents an error that occurred while loading a specific configuration field.
 ///
@@ -12,6 +13,9 @@ use crate::config_error::ConfigError;
 /// * [`MissingValue`](ConfigFieldError::MissingValue) - Required environment variable(s) not set
 /// * [`Nested`](ConfigFieldError::Nested) - Error in a nested configuration field
 /// * [`Other`](ConfigFieldError::Other) - A custom error with a message
+/// * [`SourceError`](ConfigFieldError::SourceError) - A source (e.g. a `.env` file) couldn't be read or parsed
+/// * [`FileError`](ConfigFieldError::FileError) - The file named by a `*_FILE` variable couldn't be read
+/// * [`NoVariantLoaded`](ConfigFieldError::NoVariantLoaded) - No variant of an enum configuration could be loaded
 #[derive(Debug, Clone)]
 pub enum ConfigFieldError {
     /// Failed to parse an environment variable value into the target type.
@@ -23,7 +27,8 @@ pub enum ConfigFieldError {
     ///
     /// * `field_name` - The name of the configuration field that failed
     /// * `raw` - The raw string value from the environment variable
-    /// * `message` - A detailed error message explaining why parsing failed
+    /// * `error` - The [`DecodeError`] explaining why parsing failed, with the expected format
+    ///   and a hint, if the decoder provided them
     /// * `env_var_name` - The name of the environment variable that was read
     ///
     /// # Example
@@ -48,8 +53,8 @@ pub enum ConfigFieldError {
         field_name: Option<String>,
         /// The raw string value that failed to parse.
         raw: String,
-        /// A detailed error message explaining the parsing failure.
-        message: String,
+        /// The error returned by the decoder.
+        error: DecodeError,
         /// The name of the environment variable that was read.
         env_var_name: String,
     },
@@ -148,4 +153,177 @@ pub enum ConfigFieldError {
         /// The error from loading the nested configuration.
         error: ConfigError,
     },
+
+    /// A configuration source couldn't be read or parsed.
+    ///
+    /// Unlike the other variants, this error isn't tied to a single field. It's reported
+    /// alongside field errors when a source such as a `.env` file contains invalid syntax
+    /// or can't be read at all.
+    ///
+    /// # Fields
+    ///
+    /// * `origin` - Where the error happened, usually the path of a file
+    /// * `line` - The 1-based line number, if the error can be pinned to a line
+    /// * `message` - A detailed error message
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tryphon::{Config, ConfigFieldError, DotEnv};
+    ///
+    /// #[derive(Debug, Config)]
+    /// struct AppConfig {
+    ///     #[env("PORT")]
+    ///     #[default(8080)]
+    ///     port: u16,
+    /// }
+    ///
+    /// let dotenv = DotEnv::parse("PORT=\"8080", ".env");
+    /// let err = AppConfig::load_from(&dotenv).unwrap_err();
+    ///
+    /// assert!(matches!(
+    ///     &err.field_errors[0],
+    ///     ConfigFieldError::SourceError { line: Some(1), .. }
+    /// ));
+    /// ```
+    SourceError {
+        /// Where the error happened, usually the path of a file.
+        origin: String,
+        /// The 1-based line number, if the error can be pinned to a line.
+        line: Option<usize>,
+        /// A detailed error message.
+        message: String,
+    },
+
+    /// The file named by a `*_FILE` variable couldn't be read.
+    ///
+    /// Fields with `#[env("NAME", file)]` (or in a struct marked `#[config(file_vars)]`) also
+    /// look up `NAME_FILE`, which holds the path of a file with the actual value, as used for
+    /// Docker and Kubernetes secrets. This error occurs when that file can't be read.
+    ///
+    /// # Fields
+    ///
+    /// * `field_name` - The name of the configuration field
+    /// * `env_var_name` - The name of the `*_FILE` variable
+    /// * `path` - The path of the file
+    /// * `message` - A detailed error message
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tryphon::{Config, ConfigFieldError, EnvOverrides, Secret};
+    ///
+    /// #[derive(Debug, Config)]
+    /// struct DbConfig {
+    ///     #[env("DB_PASSWORD", file)]
+    ///     password: Secret<String>,
+    /// }
+    ///
+    /// let mut overrides = EnvOverrides::init();
+    /// overrides.set("DB_PASSWORD_FILE", "/run/secrets/missing");
+    ///
+    /// let err = DbConfig::load().unwrap_err();
+    ///
+    /// assert!(matches!(
+    ///     &err.field_errors[0],
+    ///     ConfigFieldError::FileError { path, .. } if path == "/run/secrets/missing"
+    /// ));
+    /// ```
+    FileError {
+        /// The index of the field in the struct.
+        field_idx: usize,
+        /// The name of the configuration field.
+        field_name: Option<String>,
+        /// The name of the `*_FILE` variable naming the file.
+        env_var_name: String,
+        /// The path of the file that couldn't be read.
+        path: String,
+        /// A detailed error message.
+        message: String,
+    },
+
+    /// No variant of an enum configuration could be loaded.
+    ///
+    /// Variants of a `#[derive(Config)]` enum are tried in order, and the first one that loads
+    /// is used. If none of them loads, the errors of every variant are reported, grouped by
+    /// variant name.
+    ///
+    /// # Fields
+    ///
+    /// * `variant_errors` - The name of every variant with the error it failed with, in
+    ///   declaration order
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tryphon::{Config, ConfigFieldError, EnvOverrides};
+    ///
+    /// #[derive(Debug, Config)]
+    /// enum MessagingConfig {
+    ///     Kafka {
+    ///         #[env("KAFKA_BROKER")]
+    ///         broker: String,
+    ///     },
+    ///     Pulsar {
+    ///         #[env("PULSAR_BROKER")]
+    ///         broker: String,
+    ///     },
+    /// }
+    ///
+    /// let _overrides = EnvOverrides::init();
+    ///
+    /// let err = MessagingConfig::load().unwrap_err();
+    ///
+    /// assert!(matches!(
+    ///     &err.field_errors[0],
+    ///     ConfigFieldError::NoVariantLoaded { variant_errors } if variant_errors[0].0 == "Kafka"
+    /// ));
+    /// ```
+    NoVariantLoaded {
+        /// The name of every variant with the error it failed with, in declaration order.
+        variant_errors: Vec<(String, ConfigError)>,
+    },
+}
+
+impl ConfigFieldError {
+    /// Returns the message of the error, if it has one.
+    ///
+    /// For a [`ParsingError`](ConfigFieldError::ParsingError) it's the message of its
+    /// [`DecodeError`], which used to be stored in a `message` field, so code reading that
+    /// field can call this method instead. [`Other`](ConfigFieldError::Other),
+    /// [`SourceError`](ConfigFieldError::SourceError) and
+    /// [`FileError`](ConfigFieldError::FileError) return their `message`; errors made of other
+    /// errors or names, like [`MissingValue`](ConfigFieldError::MissingValue), have no message.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tryphon::{Config, EnvOverrides};
+    ///
+    /// #[derive(Debug, Config)]
+    /// struct ServerConfig {
+    ///     #[env("PORT")]
+    ///     port: u16,
+    /// }
+    ///
+    /// let mut overrides = EnvOverrides::init();
+    /// overrides.set("PORT", "not-a-number");
+    ///
+    /// let error = ServerConfig::load().unwrap_err();
+    /// assert_eq!(
+    ///     error.field_errors[0].message(),
+    ///     Some("invalid digit found in string")
+    /// );
+    /// ```
+    pub fn message(&self) -> Option<&str> {
+        match self {
+            ConfigFieldError::ParsingError { error, .. } => Some(error.message()),
+            ConfigFieldError::Other { message, .. }
+            | ConfigFieldError::SourceError { message, .. }
+            | ConfigFieldError::FileError { message, .. } => Some(message),
+            ConfigFieldError::MissingValue { .. }
+            | ConfigFieldError::Nested { .. }
+            | ConfigFieldError::NoVariantLoaded { .. } => None,
+        }
+    }
 }
@@ -1,15 +1,22 @@
+use crate::decode_error::DecodeError;
+
 /// A trait for decoding raw string values from environment variables into typed values.
 ///
 /// This trait defines how to convert a raw string value from an environment variable
 /// into a specific Rust type. Implement this trait to support custom types in your
 /// configuration structs.
 ///
+/// Implementors write [`decode_with`](ConfigValueDecoder::decode_with), which gets the options
+/// of the field and returns a [`DecodeError`], optionally with the expected format and a hint.
+/// A plain message can be turned into a `DecodeError` with `into()`.
+/// [`decode`](ConfigValueDecoder::decode) has a default implementation calling it.
+///
 /// # Examples
 ///
 /// ## Simple Custom Type
 ///
 /// ```rust
-/// use tryphon::{ConfigValueDecoder, Config};
+/// use tryphon::{Config, ConfigValueDecoder, DecodeError, DecodeOptions};
 ///
 /// #[derive(Debug, PartialEq)]
 /// enum Environment {
@@ -19,12 +26,12 @@
 /// }
 ///
 /// impl ConfigValueDecoder for Environment {
-///     fn decode(raw: String) -> Result<Self, String> {
+///     fn decode_with(raw: &str, _options: &DecodeOptions) -> Result<Self, DecodeError> {
 ///         match raw.to_lowercase().as_str() {
 ///             "dev" | "development" => Ok(Environment::Development),
 ///             "staging" => Ok(Environment::Staging),
 ///             "prod" | "production" => Ok(Environment::Production),
-///             _ => Err(format!("Unknown environment: {}", raw))
+///             _ => Err(format!("Unknown environment: {}", raw).into())
 ///         }
 ///     }
 /// }
@@ -43,6 +50,9 @@
 pub trait ConfigValueDecoder {
     /// Decodes a raw string value into the target type.
     ///
+    /// The default implementation calls [`decode_with`](Self::decode_with) with the default
+    /// options and converts its error into a message.
+    ///
     /// # Arguments
     ///
     /// * `raw` - The raw string value from the environment variable
@@ -62,6 +72,133 @@ pub trait ConfigValueDecoder {
     /// assert_eq!(result.unwrap(), 8080);
     /// ```
     fn decode(raw: String) -> Result<Self, String>
+    where
+        Self: Sized,
+    {
+        Self::decode_with(&raw, &DecodeOptions::default()).map_err(|error| error.to_string())
+    }
+
+    /// Decodes a borrowed raw string value with the options set on the field, like the
+    /// `separator` of a `#[env("HOSTS", separator = ";")]` list. This is what
+    /// `#[derive(Config)]` calls, so the raw value is only kept when decoding fails.
+    ///
+    /// This is the method to implement. The error can carry the underlying error, the expected
+    /// format and a hint, shown by the printers of [`ConfigError`](crate::ConfigError).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tryphon::{ConfigValueDecoder, DecodeOptions};
+    ///
+    /// let options = DecodeOptions::default().with_separator(";");
+    /// let result = Vec::<u16>::decode_with("80;443", &options);
+    /// assert_eq!(result.unwrap(), vec![80, 443]);
+    /// ```
+    fn decode_with(raw: &str, options: &DecodeOptions) -> Result<Self, DecodeError>
     where
         Self: Sized;
+
+    /// Returns the raw value as it's shown in errors, like the `raw` value of a
+    /// [`ParsingError`](crate::ConfigFieldError::ParsingError).
+    ///
+    /// The default implementation returns it unchanged. Override it in decoders of values that
+    /// can hold credentials, like URLs with passwords, to hide them.
+    fn redact_raw(raw: String) -> String
+    where
+        Self: Sized,
+    {
+        raw
+    }
+
+    /// Returns the raw value as it's shown in errors, given the options of the field.
+    ///
+    /// The default implementation calls [`redact_raw`](ConfigValueDecoder::redact_raw).
+    /// Collections and maps override it to redact each of their elements, split with the
+    /// separators of the options.
+    fn redact_raw_with(raw: String, _options: &DecodeOptions) -> String
+    where
+        Self: Sized,
+    {
+        Self::redact_raw(raw)
+    }
 }
+
+/// Options of a single field, passed to [`ConfigValueDecoder::decode_with`].
+///
+/// They are set with options of the `#[env]` attribute:
+///
+/// - `separator = "..."` - the separator of the elements of a collection or the entries of a
+///   map, `,` by default
+/// - `key_value_separator = "..."` - the separator of the key and the value of map entries,
+///   `=` by default
+/// - `byte_size` - decodes unsigned integer fields, and the elements of collections and maps
+///   of them, from sizes like `512MiB`, see [`ByteSize`](crate::ByteSize)
+///
+/// # Examples
+///
+/// ```rust
+/// use tryphon::Config;
+///
+/// #[derive(Config)]
+/// struct AppConfig {
+///     #[env("HOSTS", separator = ";")]
+///     hosts: Vec<String>,
+///
+///     #[env("CACHE_SIZE", byte_size)]
+///     cache_size: u64,
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DecodeOptions {
+    separator: Option<String>,
+    key_value_separator: Option<String>,
+    byte_size: bool,
+}
+
+impl DecodeOptions {
+    /// Sets the separator of the elements of a collection or the entries of a map.
+    pub fn with_separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = Some(separator.into());
+        self
+    }
+
+    /// Sets the separator of the key and the value of map entries.
+    pub fn with_key_value_separator(mut self, separator: impl Into<String>) -> Self {
+        self.key_value_separator = Some(separator.into());
+        self
+    }
+
+    /// Sets whether integers are decoded from byte sizes, like `512MiB`.
+    pub fn with_byte_size(mut self, byte_size: bool) -> Self {
+        self.byte_size = byte_size;
+        self
+    }
+
+    /// Returns the separator of the elements of a collection or the entries of a map, if set.
+    pub fn separator(&self) -> Option<&str> {
+        self.separator.as_deref()
+    }
+
+    /// Returns the separator of the key and the value of map entries, if set.
+    pub fn key_value_separator(&self) -> Option<&str> {
+        self.key_value_separator.as_deref()
+    }
+
+    /// Returns whether integers are decoded from byte sizes, like `512MiB`.
+    pub fn byte_size(&self) -> bool {
+        self.byte_size
+    }
+}
+
+/// Marks decoders that read the `byte_size` option of [`DecodeOptions`].
+///
+/// `#[env("...", byte_size)]` only compiles on fields whose type implements this trait, so the
+/// option can't be silently ignored. It's implemented by the unsigned integers,
+/// [`ByteSize`](crate::ByteSize), and `Option`, `Secret`, collections and maps of them.
+/// Implement it for custom decoders that check [`DecodeOptions::byte_size`].
+#[diagnostic::on_unimplemented(
+    message = "the `byte_size` option is not supported on `{Self}`",
+    label = "`{Self}` doesn't decode byte sizes",
+    note = "`byte_size` is supported on unsigned integers, `ByteSize`, and `Option`, `Secret`, collections and maps of them"
+)]
+pub trait ByteSizeDecoder: ConfigValueDecoder {}
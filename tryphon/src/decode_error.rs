@@ -0,0 +1,123 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+/// Error returned by [`ConfigValueDecoder::decode_with`](crate::ConfigValueDecoder::decode_with)
+/// when a raw value can't be decoded.
+///
+/// Besides the message, it can carry the underlying error (like a `ParseIntError`), a
+/// description of the expected format and a hint for fixing the value. The printers of
+/// [`ConfigError`](crate::ConfigError) show the expected format and the hint next to the message.
+///
+/// A plain message is converted into an error with `From<String>` or `From<&str>`.
+///
+/// # Examples
+///
+/// ```rust
+/// use tryphon::{ConfigValueDecoder, DecodeError, DecodeOptions};
+///
+/// struct Percentage(u8);
+///
+/// impl ConfigValueDecoder for Percentage {
+///     fn decode_with(raw: &str, _options: &DecodeOptions) -> Result<Self, DecodeError> {
+///         let expected = "an integer between 0 and 100, optionally followed by '%'";
+///
+///         match raw.trim_end_matches('%').parse::<u8>() {
+///             Ok(value) if value <= 100 => Ok(Percentage(value)),
+///             Ok(value) => Err(DecodeError::new(format!("{} is more than 100", value))
+///                 .with_expected(expected)),
+///             Err(e) => Err(DecodeError::new(e.to_string())
+///                 .with_source(e)
+///                 .with_expected(expected)
+///                 .with_hint("use a value like '75%'")),
+///         }
+///     }
+/// }
+///
+/// let error = Percentage::decode_with("150", &DecodeOptions::default()).err().unwrap();
+/// assert_eq!(error.message(), "150 is more than 100");
+/// ```
+#[derive(Debug, Clone)]
+pub struct DecodeError {
+    message: String,
+    source: Option<Arc<dyn Error + Send + Sync>>,
+    expected: Option<String>,
+    hint: Option<String>,
+}
+
+impl DecodeError {
+    /// Creates an error with a message explaining why the value couldn't be decoded.
+    pub fn new(message: impl Into<String>) -> Self {
+        DecodeError {
+            message: message.into(),
+            source: None,
+            expected: None,
+            hint: None,
+        }
+    }
+
+    /// Sets the underlying error, returned by [`Error::source`].
+    pub fn with_source(mut self, source: impl Error + Send + Sync + 'static) -> Self {
+        self.source = Some(Arc::new(source));
+        self
+    }
+
+    /// Sets a description of the expected format, like `an integer between 0 and 65535`.
+    pub fn with_expected(mut self, expected: impl Into<String>) -> Self {
+        self.expected = Some(expected.into());
+        self
+    }
+
+    /// Sets a hint for fixing the value.
+    pub fn with_hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    /// Returns the message explaining why the value couldn't be decoded.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the description of the expected format, if set.
+    pub fn expected(&self) -> Option<&str> {
+        self.expected.as_deref()
+    }
+
+    /// Returns the hint for fixing the value, if set.
+    pub fn hint(&self) -> Option<&str> {
+        self.hint.as_deref()
+    }
+
+    /// Replaces the message, keeping the source, the expected format and the hint.
+    pub(crate) fn map_message(mut self, f: impl FnOnce(String) -> String) -> Self {
+        self.message = f(self.message);
+        self
+    }
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for DecodeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| source.as_ref() as &(dyn Error + 'static))
+    }
+}
+
+impl From<String> for DecodeError {
+    fn from(message: String) -> Self {
+        DecodeError::new(message)
+    }
+}
+
+impl From<&str> for DecodeError {
+    fn from(message: &str) -> Self {
+        DecodeError::new(message)
+    }
+}
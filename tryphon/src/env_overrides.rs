@@ -106,6 +106,8 @@
 //! The `EnvOverrides` struct uses RAII (Resource Acquisition Is Initialization) to ensure cleanup:
 //! when the instance is dropped, the overrides for that thread are cleared.
 
+use crate::source::{Source, SourceValue};
+use crate::sources::OVERRIDE_ORIGIN;
 use std::cell::RefCell;
 use std::collections::HashMap;
 
@@ -244,6 +246,30 @@ impl EnvOverrides {
         })
     }
 
+    /// Returns the keys of all overrides set for the current thread, or an empty list if
+    /// overrides are not initialized.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tryphon::EnvOverrides;
+    ///
+    /// let mut overrides = EnvOverrides::init();
+    /// overrides.set("KEY", "value");
+    ///
+    /// assert_eq!(EnvOverrides::keys(), vec!["KEY"]);
+    /// ```
+    pub fn keys() -> Vec<String> {
+        TEST_OVERRIDES.with(|overrides| {
+            let overrides = overrides.borrow();
+            if let Some(ref to) = *overrides {
+                to.keys().cloned().collect()
+            } else {
+                Vec::new()
+            }
+        })
+    }
+
     /// Checks if environment variable overrides are initialized for the current thread.
     ///
     /// Returns `true` if [`init()`](EnvOverrides::init) has been called and the overrides
@@ -274,6 +300,35 @@ impl EnvOverrides {
     }
 }
 
+/// Reads only the thread-local overrides, ignoring the process environment.
+///
+/// Values are labelled with the origin `"override"`.
+///
+/// ```rust
+/// use tryphon::{Config, EnvOverrides};
+///
+/// #[derive(Config)]
+/// struct AppConfig {
+///     #[env("API_KEY")]
+///     api_key: String,
+/// }
+///
+/// let mut overrides = EnvOverrides::init();
+/// overrides.set("API_KEY", "test-key-123");
+///
+/// let config = AppConfig::load_from(&overrides).unwrap();
+/// assert_eq!(config.api_key, "test-key-123");
+/// ```
+impl Source for EnvOverrides {
+    fn lookup(&self, key: &str) -> Result<Option<SourceValue>, String> {
+        Ok(EnvOverrides::get(key).map(|value| SourceValue::new(value, OVERRIDE_ORIGIN)))
+    }
+
+    fn keys(&self) -> Result<Vec<String>, String> {
+        Ok(EnvOverrides::keys())
+    }
+}
+
 impl Drop for EnvOverrides {
     fn drop(&mut self) {
         TEST_OVERRIDES.with(|overrides| {
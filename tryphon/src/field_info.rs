@@ -0,0 +1,54 @@
+use crate::field_path::FieldPath;
+
+/// A description of a single field of a configuration, as declared with `#[env]` and
+/// `#[default]` attributes.
+///
+/// Returned by [`Config::fields`](crate::Config::fields), it's used to build the flags and the
+/// `--help` output of [`Args`](crate::Args), and can be used to document the configuration of
+/// an application.
+///
+/// # Examples
+///
+/// ```rust
+/// use tryphon::Config;
+///
+/// #[derive(Config)]
+/// struct DatabaseConfig {
+///     #[env("DB_HOST")]
+///     #[default("localhost")]
+///     host: String,
+/// }
+///
+/// #[derive(Config)]
+/// struct AppConfig {
+///     #[env("APP_PORT")]
+///     #[env("PORT")]
+///     port: u16,
+///
+///     #[config]
+///     database: DatabaseConfig,
+/// }
+///
+/// let fields = AppConfig::fields();
+///
+/// assert_eq!(fields[0].path.dotted_path(), "port");
+/// assert_eq!(fields[0].env_vars, vec!["APP_PORT", "PORT"]);
+/// assert_eq!(fields[0].default, None);
+///
+/// assert_eq!(fields[1].path.dotted_path(), "database.host");
+/// assert_eq!(fields[1].default.as_deref(), Some("localhost"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldInfo {
+    /// The path of the field, like `database.host`.
+    pub path: FieldPath,
+    /// The `#[env]` names of the field, in fallback order.
+    pub env_vars: Vec<String>,
+    /// The `#[env]` names of the field also read from a `*_FILE` variable, with
+    /// `#[env("...", file)]` or `#[config(file_vars)]`.
+    pub file_vars: Vec<String>,
+    /// The `#[credential]` names of the field, tried before the `#[env]` names.
+    pub credentials: Vec<String>,
+    /// The `#[default]` value of the field, as written in the attribute.
+    pub default: Option<String>,
+}
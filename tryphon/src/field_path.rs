@@ -0,0 +1,115 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// A path to a field in a (possibly nested) configuration, like `database.host`.
+///
+/// Elements of lists and maps of nested configurations are addressed by index and key
+/// segments, rendered in brackets like `upstreams[1].port` and `databases[ORDERS].host`.
+///
+/// Error printers use it to display hierarchical field names, and sources that organize
+/// values by structure rather than by environment variable name (like TOML files) use it
+/// to find values, see [`Source::lookup_path`](crate::Source::lookup_path).
+///
+/// # Examples
+///
+/// ```rust
+/// use tryphon::FieldPath;
+///
+/// let path = FieldPath::root().with_segment("database").with_segment("host");
+///
+/// assert_eq!(path.segments(), ["database", "host"]);
+/// assert_eq!(path.dotted_path(), "database.host");
+/// assert_eq!("database.host".parse::<FieldPath>().unwrap().segments(), path.segments());
+///
+/// let path = FieldPath::root().with_segment("upstreams").with_index(1).with_segment("port");
+///
+/// assert_eq!(path.segments(), ["upstreams", "[1]", "port"]);
+/// assert_eq!(path.dotted_path(), "upstreams[1].port");
+/// assert_eq!("upstreams[1].port".parse::<FieldPath>().unwrap(), path);
+///
+/// let path = FieldPath::root().with_segment("databases").with_key("ORDERS");
+///
+/// assert_eq!(path.dotted_path(), "databases[ORDERS]");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FieldPath {
+    segments: Vec<String>,
+}
+
+impl FieldPath {
+    /// Creates a new empty path representing the root level.
+    pub fn root() -> Self {
+        FieldPath {
+            segments: Vec::new(),
+        }
+    }
+
+    /// Creates a new path by appending a segment to this path.
+    pub fn with_segment(&self, rhs: &str) -> FieldPath {
+        let mut new_path = self.clone();
+        new_path.segments.push(rhs.to_string());
+        new_path
+    }
+
+    /// Creates a new path by appending an index segment, like `[1]`, to this path.
+    pub fn with_index(&self, idx: usize) -> FieldPath {
+        self.with_segment(&format!("[{}]", idx))
+    }
+
+    /// Creates a new path by appending a map key segment, like `[ORDERS]`, to this path.
+    pub fn with_key(&self, key: &str) -> FieldPath {
+        self.with_segment(&format!("[{}]", key))
+    }
+
+    /// Returns the segments of the path, from the outermost field to the innermost.
+    pub fn segments(&self) -> &[String] {
+        &self.segments
+    }
+
+    /// Converts the path to a dot-separated string representation, with index and key segments
+    /// appended without a dot.
+    pub fn dotted_path(&self) -> String {
+        let mut path = String::new();
+
+        for segment in &self.segments {
+            if !path.is_empty() && !segment.starts_with('[') {
+                path.push('.');
+            }
+            path.push_str(segment);
+        }
+
+        path
+    }
+}
+
+impl FromStr for FieldPath {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            Ok(FieldPath::root())
+        } else {
+            let mut segments = Vec::new();
+
+            for part in s.split('.') {
+                let (name, indices) = part.split_at(part.find('[').unwrap_or(part.len()));
+
+                if !name.is_empty() || indices.is_empty() {
+                    segments.push(name.to_string());
+                }
+
+                for index in indices.split_inclusive(']') {
+                    segments.push(index.to_string());
+                }
+            }
+
+            Ok(FieldPath { segments })
+        }
+    }
+}
+
+impl Display for FieldPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.dotted_path())
+    }
+}
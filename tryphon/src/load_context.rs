@@ -0,0 +1,574 @@
+use crate::config::Config;
+use crate::config_error::ConfigError;
+use crate::config_field_error::ConfigFieldError;
+use crate::decode_error::DecodeError;
+use crate::field_path::FieldPath;
+use crate::load_report::{FieldProvenance, FieldReport, LoadReport};
+use crate::source::{Source, SourceValue};
+use crate::sources::{DEFAULT_SIZE_LIMIT, read_value_file};
+use std::cell::RefCell;
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::rc::Rc;
+
+/// Suffix of variables holding the path of a file with the actual value, like `DB_PASSWORD_FILE`.
+const FILE_VAR_SUFFIX: &str = "_FILE";
+
+/// State threaded through the code generated by `#[derive(Config)]`.
+///
+/// Holds the [`Source`] values are read from, the [`FieldPath`] of the configuration
+/// currently being loaded, the prefix of its `#[env]` names and, optionally, the [`LoadReport`]
+/// being recorded. This is an implementation detail of the derive macro; use [`Config::load_from`](crate::Config::load_from)
+/// instead.
+#[doc(hidden)]
+#[derive(Clone)]
+pub struct LoadContext<'a> {
+    source: &'a dyn Source,
+    path: FieldPath,
+    prefix: String,
+    report: Option<&'a RefCell<LoadReport>>,
+    file_vars: bool,
+    declared: Rc<BTreeSet<String>>,
+}
+
+/// An error found while looking up the raw value of a field.
+#[doc(hidden)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LookupError {
+    /// The source failed to read the value.
+    Source(String),
+    /// The file named by a `*_FILE` variable couldn't be read.
+    File {
+        env_var_name: String,
+        path: String,
+        message: String,
+    },
+}
+
+impl LookupError {
+    /// Converts the error into the [`ConfigFieldError`] reported for the field.
+    pub fn into_field_error(
+        self,
+        field_name: Option<String>,
+        field_idx: usize,
+    ) -> ConfigFieldError {
+        match self {
+            LookupError::Source(message) => ConfigFieldError::Other {
+                field_idx,
+                field_name,
+                message,
+            },
+            LookupError::File {
+                env_var_name,
+                path,
+                message,
+            } => ConfigFieldError::FileError {
+                field_idx,
+                field_name,
+                env_var_name,
+                path,
+                message,
+            },
+        }
+    }
+}
+
+impl<'a> LoadContext<'a> {
+    /// Creates a context for loading a top-level configuration from `source`.
+    pub fn new(source: &'a dyn Source) -> Self {
+        LoadContext {
+            source,
+            path: FieldPath::root(),
+            prefix: String::new(),
+            report: None,
+            file_vars: false,
+            declared: Rc::default(),
+        }
+    }
+
+    /// Creates a context for loading a top-level configuration from `source`, recording
+    /// where every field came from in `report`.
+    pub fn with_report(source: &'a dyn Source, report: &'a RefCell<LoadReport>) -> Self {
+        LoadContext {
+            report: Some(report),
+            ..LoadContext::new(source)
+        }
+    }
+
+    /// Records the `#[env]` names of the fields of the configuration `C` loaded with this
+    /// context, so variables of other fields aren't taken for entries of maps whose prefix they
+    /// share, see [`load_map`](LoadContext::load_map).
+    pub fn declaring<C: Config>(self) -> Self {
+        let mut fields = Vec::new();
+        C::describe_fields(&self.path, &self.prefix, &mut fields);
+
+        LoadContext {
+            declared: Rc::new(
+                fields
+                    .into_iter()
+                    .flat_map(|field| field.env_vars)
+                    .collect(),
+            ),
+            ..self
+        }
+    }
+
+    /// Returns the path of the configuration currently being loaded.
+    pub fn path(&self) -> &FieldPath {
+        &self.path
+    }
+
+    /// Creates a context for loading the nested configuration stored in field `segment`.
+    pub fn nested(&self, segment: &str) -> LoadContext<'a> {
+        LoadContext {
+            path: self.path.with_segment(segment),
+            ..self.clone()
+        }
+    }
+
+    /// Creates a context in which every `#[env]` name is prefixed with `prefix`, used by
+    /// `#[config(prefix = "...")]` and [`Config::load_with_prefix`](crate::Config::load_with_prefix).
+    /// The prefix is appended to the prefix of this context, so prefixes of nested
+    /// configurations stack.
+    pub fn with_prefix(&self, prefix: &str) -> LoadContext<'a> {
+        LoadContext {
+            prefix: format!("{}{}", self.prefix, prefix),
+            ..self.clone()
+        }
+    }
+
+    /// Creates a context in which every `#[env]` name is also looked up with the `_FILE`
+    /// suffix, used by `#[config(file_vars)]`. Nested configurations inherit it.
+    pub fn with_file_vars(&self) -> LoadContext<'a> {
+        LoadContext {
+            file_vars: true,
+            ..self.clone()
+        }
+    }
+
+    /// Returns all names looked up for a field: each of `env_vars` with the prefix of this
+    /// context, followed by its `_FILE` variant if it's listed in `file_vars` (or `*_FILE`
+    /// variables are enabled for every field).
+    pub fn env_var_names(&self, env_vars: &[&str], file_vars: &[&str]) -> Vec<String> {
+        let mut names = Vec::with_capacity(env_vars.len());
+
+        for env_var_name in env_vars {
+            let prefixed = format!("{}{}", self.prefix, env_var_name);
+
+            if self.file_vars || file_vars.contains(env_var_name) {
+                names.push(prefixed.clone());
+                names.push(format!("{}{}", prefixed, FILE_VAR_SUFFIX));
+            } else {
+                names.push(prefixed);
+            }
+        }
+
+        names
+    }
+
+    /// Looks up the raw value of field `segment`.
+    ///
+    /// The `credentials` names are tried first, with [`Source::lookup_credential`]. Then see
+    /// [`Source::resolve`] for how `env_vars` and the path of the field are used. If the
+    /// value is found in a `*_FILE` variable (see [`env_var_names`](LoadContext::env_var_names)),
+    /// the file it names is read and its contents, without the trailing newline, are returned.
+    /// If a value is found, it's recorded in the report.
+    pub fn lookup(
+        &self,
+        env_vars: &[&str],
+        file_vars: &[&str],
+        credentials: &[&str],
+        segment: &str,
+    ) -> Result<Option<(SourceValue, String)>, LookupError> {
+        for name in credentials {
+            let credential = self
+                .source
+                .lookup_credential(name)
+                .map_err(LookupError::Source)?;
+
+            if let Some(value) = credential {
+                self.record(
+                    &[],
+                    segment,
+                    FieldProvenance::Found {
+                        key: name.to_string(),
+                        origin: value.origin.clone(),
+                    },
+                );
+
+                return Ok(Some((value, name.to_string())));
+            }
+        }
+
+        let names = self.env_var_names(env_vars, file_vars);
+        let name_refs = names.iter().map(String::as_str).collect::<Vec<_>>();
+
+        let resolved = self
+            .source
+            .resolve(&name_refs, &self.path.with_segment(segment))
+            .map_err(LookupError::Source)?;
+
+        let Some((value, key)) = resolved else {
+            return Ok(None);
+        };
+
+        let tried = names.iter().position(|name| *name == key);
+
+        // Every name that isn't one of the declared `#[env]` names is a `*_FILE` variable.
+        let is_file_var = tried.is_some()
+            && !env_vars
+                .iter()
+                .any(|env_var_name| key == format!("{}{}", self.prefix, env_var_name));
+
+        let value = if is_file_var {
+            read_file_var(&key, value.value)?
+        } else {
+            value
+        };
+
+        self.record(
+            &names[..tried.unwrap_or(names.len())],
+            segment,
+            FieldProvenance::Found {
+                key: key.clone(),
+                origin: value.origin.clone(),
+            },
+        );
+
+        Ok(Some((value, key)))
+    }
+
+    /// Records in the report that no value was found for field `segment`, so its default
+    /// was used or it was left unset.
+    pub fn record_missing(
+        &self,
+        env_vars: &[&str],
+        file_vars: &[&str],
+        segment: &str,
+        provenance: FieldProvenance,
+    ) {
+        self.record(
+            &self.env_var_names(env_vars, file_vars),
+            segment,
+            provenance,
+        );
+    }
+
+    /// Loads an optional nested configuration, stored in an `Option` field marked with
+    /// `#[config]`, with this context.
+    ///
+    /// Returns `None` if no field of `C` has a value, see [`has_values`](LoadContext::has_values).
+    /// Otherwise the section is considered configured and `C` is loaded, so fields missing from
+    /// a partially configured section are reported as [`ConfigFieldError::MissingValue`] errors.
+    pub fn load_optional<C: Config>(&self) -> Result<Option<C>, ConfigError> {
+        // If the source fails, loading the fields reports the error.
+        if let Ok(false) = self.has_values::<C>() {
+            return Ok(None);
+        }
+
+        C::load_fields(self).map(Some)
+    }
+
+    /// Loads the list stored in field `segment`, marked with `#[config(indexed = "...")]`.
+    ///
+    /// Element `i` is loaded with the prefix `{prefix}{i}_` (after the prefix of this
+    /// context) and the path `segment[i]`, for consecutive indices starting at 0 until no
+    /// value is found for any field of the element, see [`has_values`](LoadContext::has_values).
+    /// Errors of the elements are wrapped in [`ConfigFieldError::Nested`] errors named after
+    /// their index, like `[1]`.
+    pub fn load_indexed<C: Config>(
+        &self,
+        segment: &str,
+        prefix: &str,
+    ) -> Result<Vec<C>, ConfigError> {
+        let list = self.nested(segment);
+
+        let mut elements = Vec::new();
+        let mut field_errors = Vec::new();
+
+        for idx in 0.. {
+            let element = LoadContext {
+                path: list.path.with_index(idx),
+                ..list.with_prefix(&format!("{}{}_", prefix, idx))
+            };
+            let field_name = Some(format!("[{}]", idx));
+
+            match element.has_values::<C>() {
+                Ok(true) => {}
+                Ok(false) => break,
+                Err(message) => {
+                    field_errors.push(ConfigFieldError::Other {
+                        field_idx: idx,
+                        field_name,
+                        message,
+                    });
+                    break;
+                }
+            }
+
+            match C::load_fields(&element) {
+                Ok(value) => elements.push(value),
+                Err(error) => field_errors.push(ConfigFieldError::Nested {
+                    field_idx: idx,
+                    field_name,
+                    error,
+                }),
+            }
+        }
+
+        if field_errors.is_empty() {
+            Ok(elements)
+        } else {
+            Err(ConfigError { field_errors })
+        }
+    }
+
+    /// Loads the map stored in field `segment`, marked with `#[config(map = "...")]`.
+    ///
+    /// Keys are discovered from the keys of the source (see [`Source::keys`]) that start with
+    /// `prefix` (after the prefix of this context) and end with `_` followed by the `#[env]`
+    /// name of a field of `C`, or its `_FILE` variant: with the prefix `DB_`, the variable
+    /// `DB_ORDERS_HOST` adds the key `ORDERS` if `C` has a field read from `HOST`. When several
+    /// names match, the longest one is used. Variables of other fields of the configuration
+    /// (see [`declaring`](LoadContext::declaring)) are skipped, even if they match. The keys of
+    /// the table at the path of the map are added too (see [`Source::keys_at`]), so maps can be
+    /// read from structured documents. The entry with key `k` is loaded with the prefix
+    /// `{prefix}{k}_` and the path `segment[k]`, and its errors are wrapped in
+    /// [`ConfigFieldError::Nested`] errors named after the key, like `[ORDERS]`.
+    pub fn load_map<C: Config, M: FromIterator<(String, C)>>(
+        &self,
+        segment: &str,
+        prefix: &str,
+    ) -> Result<M, ConfigError> {
+        let map = self.nested(segment).with_prefix(prefix);
+
+        let mut env_vars = Vec::new();
+        C::describe_fields(&map.path, "", &mut env_vars);
+
+        let mut names = env_vars
+            .into_iter()
+            .flat_map(|field| field.env_vars)
+            .flat_map(|name| {
+                [
+                    format!("_{}{}", name, FILE_VAR_SUFFIX),
+                    format!("_{}", name),
+                ]
+            })
+            .collect::<Vec<_>>();
+        names.sort_by_key(|name| std::cmp::Reverse(name.len()));
+
+        let source_keys = self.source.keys().map_err(|message| ConfigError {
+            // Reported for all keys of the map, as `segment[*]`.
+            field_errors: vec![ConfigFieldError::Other {
+                field_idx: 0,
+                field_name: Some("[*]".to_string()),
+                message,
+            }],
+        })?;
+
+        let table_keys = self
+            .source
+            .keys_at(&map.path)
+            .map_err(|message| ConfigError {
+                field_errors: vec![ConfigFieldError::Other {
+                    field_idx: 0,
+                    field_name: Some("[*]".to_string()),
+                    message,
+                }],
+            })?;
+
+        let is_declared = |source_key: &str| {
+            self.declared.contains(source_key)
+                || source_key
+                    .strip_suffix(FILE_VAR_SUFFIX)
+                    .is_some_and(|name| self.declared.contains(name))
+        };
+
+        let keys = source_keys
+            .iter()
+            .filter(|source_key| !is_declared(source_key))
+            .filter_map(|source_key| {
+                let rest = source_key.strip_prefix(&map.prefix)?;
+                names
+                    .iter()
+                    .find_map(|name| rest.strip_suffix(name.as_str()))
+                    .filter(|key| !key.is_empty())
+            })
+            .chain(table_keys.iter().map(String::as_str))
+            .collect::<BTreeSet<_>>();
+
+        let mut entries = Vec::new();
+        let mut field_errors = Vec::new();
+
+        for (idx, key) in keys.into_iter().enumerate() {
+            let entry = LoadContext {
+                path: map.path.with_key(key),
+                ..map.with_prefix(&format!("{}_", key))
+            };
+
+            match C::load_fields(&entry) {
+                Ok(value) => entries.push((key.to_string(), value)),
+                Err(error) => field_errors.push(ConfigFieldError::Nested {
+                    field_idx: idx,
+                    field_name: Some(format!("[{}]", key)),
+                    error,
+                }),
+            }
+        }
+
+        if field_errors.is_empty() {
+            Ok(entries.into_iter().collect())
+        } else {
+            Err(ConfigError { field_errors })
+        }
+    }
+
+    /// Returns `true` if the source has a value for any field of the configuration `C`
+    /// loaded with this context, looking up the `#[credential]` names, the prefixed `#[env]`
+    /// names, their `_FILE` variants where file variables are enabled, and the paths of the
+    /// fields.
+    pub fn has_values<C: Config>(&self) -> Result<bool, String> {
+        let mut fields = Vec::new();
+        C::describe_fields(&self.path, &self.prefix, &mut fields);
+
+        for field in fields {
+            for name in &field.credentials {
+                if self.source.lookup_credential(name)?.is_some() {
+                    return Ok(true);
+                }
+            }
+
+            for env_var_name in &field.env_vars {
+                if self.source.lookup(env_var_name)?.is_some() {
+                    return Ok(true);
+                }
+
+                if (self.file_vars || field.file_vars.contains(env_var_name))
+                    && self
+                        .source
+                        .lookup(&format!("{}{}", env_var_name, FILE_VAR_SUFFIX))?
+                        .is_some()
+                {
+                    return Ok(true);
+                }
+            }
+
+            if self.source.lookup_path(&field.path)?.is_some() {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Selects the variant of an enum marked with `#[config(tag = "...")]`, reading the `tag`
+    /// variable (with the prefix of this context) as field `segment`.
+    ///
+    /// Its value is matched case-insensitively against the `names` of every variant, and the
+    /// index of the matching variant is returned. If the variable isn't set or matches no
+    /// variant, the error lists it as a [`ConfigFieldError::MissingValue`] or
+    /// [`ConfigFieldError::ParsingError`].
+    pub fn select_variant(
+        &self,
+        segment: &str,
+        tag: &str,
+        names: &[&[&str]],
+    ) -> Result<usize, ConfigError> {
+        let field_name = Some(segment.to_string());
+
+        let field_error = match self.lookup(&[tag], &[], &[], segment) {
+            Ok(Some((SourceValue { value, path, .. }, env_var_name))) => {
+                let lowercased = value.to_lowercase();
+                let selected = names.iter().position(|variant_names| {
+                    variant_names
+                        .iter()
+                        .any(|name| name.to_lowercase() == lowercased)
+                });
+
+                if let Some(idx) = selected {
+                    return Ok(idx);
+                }
+
+                let expected = names
+                    .iter()
+                    .filter_map(|variant_names| variant_names.first())
+                    .copied()
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                ConfigFieldError::ParsingError {
+                    field_idx: 0,
+                    field_name,
+                    error: decode_error_with_path(
+                        DecodeError::new(format!("unknown variant '{}'", value))
+                            .with_expected(format!("one of: {}", expected)),
+                        path.as_deref(),
+                    ),
+                    raw: value,
+                    env_var_name,
+                }
+            }
+            Ok(None) => ConfigFieldError::MissingValue {
+                field_name,
+                field_idx: 0,
+                env_vars: self.env_var_names(&[tag], &[]),
+            },
+            Err(error) => error.into_field_error(field_name, 0),
+        };
+
+        Err(ConfigError {
+            field_errors: vec![field_error],
+        })
+    }
+
+    /// Returns the number of fields recorded so far, to be passed to
+    /// [`rollback_report`](LoadContext::rollback_report).
+    pub fn report_checkpoint(&self) -> usize {
+        self.report
+            .map(|report| report.borrow().fields().len())
+            .unwrap_or_default()
+    }
+
+    /// Forgets fields recorded after `checkpoint`, used when an enum variant fails to load
+    /// and the next one is tried.
+    pub fn rollback_report(&self, checkpoint: usize) {
+        if let Some(report) = self.report {
+            report.borrow_mut().truncate(checkpoint);
+        }
+    }
+
+    fn record(&self, missing: &[String], segment: &str, provenance: FieldProvenance) {
+        if let Some(report) = self.report {
+            report.borrow_mut().push(FieldReport {
+                path: self.path.with_segment(segment),
+                provenance,
+                missing: missing.to_vec(),
+            });
+        }
+    }
+}
+
+/// Reads the file named by the `*_FILE` variable `env_var_name`, stripping one trailing newline.
+/// Files larger than [`DEFAULT_SIZE_LIMIT`] are reported as errors.
+fn read_file_var(env_var_name: &str, path: String) -> Result<SourceValue, LookupError> {
+    match read_value_file(Path::new(&path), DEFAULT_SIZE_LIMIT) {
+        Ok(contents) => Ok(SourceValue::from_file(contents, path)),
+        Err(e) => Err(LookupError::File {
+            env_var_name: env_var_name.to_string(),
+            path,
+            message: e.to_string(),
+        }),
+    }
+}
+
+/// Adds the file the value was read from, if any, to the message of the [`DecodeError`] of a
+/// [`ConfigFieldError::ParsingError`].
+#[doc(hidden)]
+pub fn decode_error_with_path(error: DecodeError, path: Option<&Path>) -> DecodeError {
+    match path {
+        Some(path) => {
+            error.map_message(|message| format!("{} (read from '{}')", message, path.display()))
+        }
+        None => error,
+    }
+}
@@ -0,0 +1,36 @@
+/// The result of [`Config::load_with_args`](crate::Config::load_with_args): either the loaded
+/// configuration, or the help text if `--help` or `-h` was passed.
+///
+/// Printing the help and exiting is left to the application.
+///
+/// # Examples
+///
+/// ```rust no_run
+/// use tryphon::{Config, ErrorPrintMode, LoadOutcome};
+///
+/// #[derive(Config)]
+/// struct AppConfig {
+///     #[env("PORT")]
+///     port: u16,
+/// }
+///
+/// let config = match AppConfig::load_with_args() {
+///     Ok(LoadOutcome::Loaded(config)) => config,
+///     Ok(LoadOutcome::Help(help)) => {
+///         print!("{}", help);
+///         std::process::exit(0);
+///     }
+///     Err(e) => {
+///         eprintln!("{}", e.pretty_print(ErrorPrintMode::List));
+///         std::process::exit(1);
+///     }
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoadOutcome<C> {
+    /// The configuration was loaded.
+    Loaded(C),
+    /// Help was requested, with the text listing the flag of every field, see
+    /// [`Args::help`](crate::Args::help).
+    Help(String),
+}
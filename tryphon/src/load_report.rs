@@ -0,0 +1,136 @@
+use crate::field_path::FieldPath;
+use std::fmt::{Display, Formatter};
+
+/// Where the value of a single field came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldProvenance {
+    /// The value was read from a [`Source`](crate::Source).
+    Found {
+        /// The key the value was found under: the environment variable name, or the dotted
+        /// path of the field for structured sources like TOML files.
+        key: String,
+        /// The origin label of the value, e.g. `"environment"`, `"override"` or a file name.
+        origin: String,
+    },
+    /// No value was found, so the `#[default]` value was used.
+    Default,
+    /// No value was found for an `Option` field, so it was set to `None`.
+    Unset,
+}
+
+/// How a single field of a configuration was loaded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldReport {
+    /// The path of the field, like `database.port`.
+    pub path: FieldPath,
+    /// Where the value came from.
+    pub provenance: FieldProvenance,
+    /// The `#[env]` names that were tried and not set before the value was found.
+    ///
+    /// If the value came from a default, or from the path of the field, these are all of the
+    /// field's `#[env]` names.
+    pub missing: Vec<String>,
+}
+
+impl FieldReport {
+    /// Returns `true` if the `#[default]` value of the field was used.
+    pub fn default_used(&self) -> bool {
+        self.provenance == FieldProvenance::Default
+    }
+}
+
+impl Display for FieldReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: ", self.path)?;
+
+        match &self.provenance {
+            FieldProvenance::Found { key, origin } => write!(f, "from {} ({})", key, origin)?,
+            FieldProvenance::Default => write!(f, "default")?,
+            FieldProvenance::Unset => write!(f, "unset")?,
+        }
+
+        if !self.missing.is_empty() {
+            write!(f, ", missing: {}", self.missing.join(", "))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A record of where every field of a loaded configuration came from.
+///
+/// Returned by [`Config::load_with_report`](crate::Config::load_with_report) and
+/// [`Config::load_from_with_report`](crate::Config::load_from_with_report). Fields are listed
+/// in the order they were loaded, and can be looked up by their dotted path. The report never
+/// contains the values themselves, so it's safe to log even if the configuration holds secrets.
+///
+/// # Examples
+///
+/// ```rust
+/// use tryphon::{Config, EnvOverrides, FieldProvenance};
+///
+/// #[derive(Config)]
+/// struct AppConfig {
+///     #[env("APP_PORT")]
+///     #[env("PORT")]
+///     port: u16,
+///
+///     #[env("LOG_LEVEL")]
+///     #[default("info")]
+///     log_level: String,
+/// }
+///
+/// let mut overrides = EnvOverrides::init();
+/// overrides.set("PORT", "9090");
+///
+/// let (config, report) = AppConfig::load_with_report().unwrap();
+///
+/// let port = report.get("port").unwrap();
+/// assert_eq!(
+///     port.provenance,
+///     FieldProvenance::Found { key: "PORT".to_string(), origin: "override".to_string() }
+/// );
+/// assert_eq!(port.missing, vec!["APP_PORT"]);
+///
+/// assert!(report.get("log_level").unwrap().default_used());
+///
+/// assert_eq!(
+///     report.to_string(),
+///     "port: from PORT (override), missing: APP_PORT\nlog_level: default, missing: LOG_LEVEL\n"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LoadReport {
+    fields: Vec<FieldReport>,
+}
+
+impl LoadReport {
+    /// Returns the report of the field with the given dotted path, like `database.port`.
+    pub fn get(&self, path: &str) -> Option<&FieldReport> {
+        self.fields
+            .iter()
+            .find(|field| field.path.dotted_path() == path)
+    }
+
+    /// Returns the reports of all loaded fields, in the order they were loaded.
+    pub fn fields(&self) -> &[FieldReport] {
+        &self.fields
+    }
+
+    pub(crate) fn push(&mut self, field: FieldReport) {
+        self.fields.push(field);
+    }
+
+    pub(crate) fn truncate(&mut self, len: usize) {
+        self.fields.truncate(len);
+    }
+}
+
+impl Display for LoadReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for field in &self.fields {
+            writeln!(f, "{}", field)?;
+        }
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use crate::printer::field_path::FieldPath;
+use crate::field_path::FieldPath;
 use crate::{ConfigError, ConfigFieldError};
 
 pub(crate) struct ListPrinter {
@@ -29,19 +29,29 @@ impl ListPrinter {
                 ConfigFieldError::ParsingError {
                     field_name,
                     field_idx,
-                    message,
+                    error,
                     env_var_name,
                     raw,
                 } => {
                     let field_path = parent_field_path
                         .with_segment(field_name.clone().unwrap_or(field_idx.to_string()).as_str());
-                    self.buffer.push(format!(
+                    let mut lines = vec![format!(
                         "Parsing error for env var '{}' for field '{}': {} (raw value: {})",
                         env_var_name,
                         field_path.dotted_path(),
-                        message,
+                        error.message(),
                         raw
-                    ));
+                    )];
+
+                    if let Some(expected) = error.expected() {
+                        lines.push(format!("  expected {}", expected));
+                    }
+
+                    if let Some(hint) = error.hint() {
+                        lines.push(format!("  hint: {}", hint));
+                    }
+
+                    self.buffer.push(lines.join("\n"));
                 }
                 ConfigFieldError::MissingValue {
                     field_name,
@@ -69,6 +79,65 @@ impl ListPrinter {
                         field_path, message
                     ));
                 }
+                ConfigFieldError::FileError {
+                    field_name,
+                    field_idx,
+                    env_var_name,
+                    path,
+                    message,
+                } => {
+                    let field_path = parent_field_path
+                        .with_segment(field_name.clone().unwrap_or(field_idx.to_string()).as_str());
+                    self.buffer.push(format!(
+                        "Failed to read file '{}' from env var '{}' for field '{}': {}",
+                        path, env_var_name, field_path, message
+                    ));
+                }
+                ConfigFieldError::NoVariantLoaded { variant_errors } => {
+                    let mut lines = vec![if parent_field_path.segments().is_empty() {
+                        "No variant could be loaded:".to_string()
+                    } else {
+                        format!(
+                            "No variant could be loaded for field '{}':",
+                            parent_field_path
+                        )
+                    }];
+
+                    for (variant_name, ConfigError { field_errors }) in variant_errors {
+                        let mut variant_printer = ListPrinter::new();
+                        variant_printer
+                            .print_errors_as_list(field_errors, parent_field_path.clone());
+
+                        lines.push(format!("  {}:", variant_name));
+                        lines.extend(
+                            variant_printer
+                                .buffer
+                                .iter()
+                                .flat_map(|error| error.lines())
+                                .map(|line| format!("    {}", line)),
+                        );
+                    }
+
+                    self.buffer.push(lines.join("\n"));
+                }
+                ConfigFieldError::SourceError {
+                    origin,
+                    line: Some(line),
+                    message,
+                } => {
+                    self.buffer.push(format!(
+                        "Error in '{}' at line {}: {}",
+                        origin, line, message
+                    ));
+                }
+                ConfigFieldError::SourceError {
+                    origin,
+                    line: None,
+                    message,
+                } => {
+                    self.buffer
+                        .push(format!("Error in '{}': {}", origin, message));
+                }
             }
         }
     }
@@ -83,6 +152,7 @@ impl ListPrinter {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::DecodeError;
 
     #[test]
     fn test_empty_error_list() {
@@ -100,7 +170,7 @@ mod tests {
             field_idx: 0,
             field_name: Some("port".to_string()),
             raw: "invalid".to_string(),
-            message: "invalid digit found in string".to_string(),
+            error: "invalid digit found in string".into(),
             env_var_name: "PORT".to_string(),
         }];
 
@@ -144,6 +214,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_source_errors() {
+        let mut printer = ListPrinter::new();
+        let errors = vec![
+            ConfigFieldError::SourceError {
+                origin: ".env".to_string(),
+                line: Some(3),
+                message: "unterminated double quote".to_string(),
+            },
+            ConfigFieldError::SourceError {
+                origin: ".env.local".to_string(),
+                line: None,
+                message: "No such file or directory".to_string(),
+            },
+        ];
+
+        let result = printer.print(&errors);
+
+        assert!(result.contains("Found 2 configuration error(s):"));
+        assert!(result.contains("Error in '.env' at line 3: unterminated double quote"));
+        assert!(result.contains("Error in '.env.local': No such file or directory"));
+    }
+
+    #[test]
+    fn test_file_error() {
+        let mut printer = ListPrinter::new();
+        let errors = vec![ConfigFieldError::FileError {
+            field_idx: 0,
+            field_name: Some("password".to_string()),
+            env_var_name: "DB_PASSWORD_FILE".to_string(),
+            path: "/run/secrets/db_password".to_string(),
+            message: "No such file or directory".to_string(),
+        }];
+
+        let result = printer.print(&errors);
+
+        assert!(result.contains(
+            "Failed to read file '/run/secrets/db_password' from env var 'DB_PASSWORD_FILE' for field 'password': No such file or directory"
+        ));
+    }
+
     #[test]
     fn test_deeply_nested_errors() {
         let mut printer = ListPrinter::new();
@@ -153,7 +264,7 @@ mod tests {
             field_idx: 0,
             field_name: Some("pool_size".to_string()),
             raw: "not_a_number".to_string(),
-            message: "invalid digit found in string".to_string(),
+            error: "invalid digit found in string".into(),
             env_var_name: "POOL_SIZE".to_string(),
         }];
 
@@ -195,7 +306,7 @@ mod tests {
             field_idx: 2,
             field_name: None,
             raw: "invalid".to_string(),
-            message: "parse error".to_string(),
+            error: "parse error".into(),
             env_var_name: "FIELD_2".to_string(),
         }];
 
@@ -220,4 +331,78 @@ mod tests {
         assert!(result.contains("Found 1 configuration error(s):"));
         assert!(result.contains("field 'values.3.2'"));
     }
+
+    #[test]
+    fn test_no_variant_loaded_error() {
+        let mut printer = ListPrinter::new();
+
+        let errors = vec![ConfigFieldError::Nested {
+            field_idx: 0,
+            field_name: Some("messaging".to_string()),
+            error: ConfigError {
+                field_errors: vec![ConfigFieldError::NoVariantLoaded {
+                    variant_errors: vec![
+                        (
+                            "Kafka".to_string(),
+                            ConfigError {
+                                field_errors: vec![ConfigFieldError::MissingValue {
+                                    field_name: Some("broker".to_string()),
+                                    field_idx: 0,
+                                    env_vars: vec!["KAFKA_BROKER".to_string()],
+                                }],
+                            },
+                        ),
+                        (
+                            "Pulsar".to_string(),
+                            ConfigError {
+                                field_errors: vec![ConfigFieldError::ParsingError {
+                                    field_idx: 0,
+                                    field_name: Some("port".to_string()),
+                                    raw: "abc".to_string(),
+                                    error: "invalid digit found in string".into(),
+                                    env_var_name: "PULSAR_PORT".to_string(),
+                                }],
+                            },
+                        ),
+                    ],
+                }],
+            },
+        }];
+
+        let result = printer.print(&errors);
+
+        assert_eq!(
+            result,
+            "Found 1 configuration error(s):\n\
+             No variant could be loaded for field 'messaging':\n\
+             \x20 Kafka:\n\
+             \x20   Missing value for field 'messaging.broker', tried env vars: KAFKA_BROKER\n\
+             \x20 Pulsar:\n\
+             \x20   Parsing error for env var 'PULSAR_PORT' for field 'messaging.port': invalid digit found in string (raw value: abc)"
+        );
+    }
+
+    #[test]
+    fn test_parsing_error_with_expected_format_and_hint() {
+        let mut printer = ListPrinter::new();
+        let errors = vec![ConfigFieldError::ParsingError {
+            field_idx: 0,
+            field_name: Some("port".to_string()),
+            raw: "http".to_string(),
+            error: DecodeError::new("invalid digit found in string")
+                .with_expected("an integer between 0 and 65535")
+                .with_hint("set the port number, like 8080"),
+            env_var_name: "PORT".to_string(),
+        }];
+
+        let result = printer.print(&errors);
+
+        assert_eq!(
+            result,
+            "Found 1 configuration error(s):\n\
+             Parsing error for env var 'PORT' for field 'port': invalid digit found in string (raw value: http)\n\
+             \x20 expected an integer between 0 and 65535\n\
+             \x20 hint: set the port number, like 8080"
+        );
+    }
 }
@@ -1,3 +1,2 @@
-pub(crate) mod field_path;
 pub(crate) mod list_printer;
 pub(crate) mod table_printer;
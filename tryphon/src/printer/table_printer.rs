@@ -1,4 +1,4 @@
-use crate::printer::field_path::FieldPath;
+use crate::field_path::FieldPath;
 use crate::{ConfigError, ConfigFieldError};
 
 pub(crate) struct TablePrinter {
@@ -30,16 +30,23 @@ impl TablePrinter {
                     field_name,
                     field_idx,
                     raw,
-                    message,
+                    error,
                     env_var_name,
                 } => {
                     let field_path = parent_field_path
                         .with_segment(field_name.clone().unwrap_or(field_idx.to_string()).as_str());
-                    self.rows.push((
-                        field_path.dotted_path(),
-                        env_var_name.clone(),
-                        format!("{} (raw value: '{}')", message, raw),
-                    ));
+                    let mut details = format!("{} (raw value: '{}')", error.message(), raw);
+
+                    if let Some(expected) = error.expected() {
+                        details.push_str(&format!("; expected {}", expected));
+                    }
+
+                    if let Some(hint) = error.hint() {
+                        details.push_str(&format!("; hint: {}", hint));
+                    }
+
+                    self.rows
+                        .push((field_path.dotted_path(), env_var_name.clone(), details));
                 }
                 ConfigFieldError::MissingValue {
                     field_name,
@@ -64,6 +71,49 @@ impl TablePrinter {
                     self.rows
                         .push((field_path.dotted_path(), "-".to_string(), message.clone()));
                 }
+                ConfigFieldError::FileError {
+                    field_name,
+                    field_idx,
+                    env_var_name,
+                    path,
+                    message,
+                } => {
+                    let field_path = parent_field_path
+                        .with_segment(field_name.clone().unwrap_or(field_idx.to_string()).as_str());
+                    self.rows.push((
+                        field_path.dotted_path(),
+                        env_var_name.clone(),
+                        format!("Failed to read '{}': {}", path, message),
+                    ));
+                }
+                ConfigFieldError::NoVariantLoaded { variant_errors } => {
+                    for (variant_name, ConfigError { field_errors }) in variant_errors {
+                        let mut variant_printer = TablePrinter::new();
+                        variant_printer
+                            .collect_errors_as_rows(field_errors, parent_field_path.clone());
+
+                        self.rows.extend(variant_printer.rows.into_iter().map(
+                            |(field_path, env_vars, details)| {
+                                (
+                                    field_path,
+                                    env_vars,
+                                    format!("Variant {}: {}", variant_name, details),
+                                )
+                            },
+                        ));
+                    }
+                }
+                ConfigFieldError::SourceError {
+                    origin,
+                    line,
+                    message,
+                } => {
+                    let location = match line {
+                        Some(line) => format!("{}:{}", origin, line),
+                        None => origin.clone(),
+                    };
+                    self.rows.push((location, "-".to_string(), message.clone()));
+                }
             }
         }
     }
@@ -157,6 +207,7 @@ fn format_row(cells: &[&str; 3], widths: &[usize; 3]) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::DecodeError;
 
     #[test]
     fn test_empty_error_list() {
@@ -174,7 +225,7 @@ mod tests {
             field_idx: 0,
             field_name: Some("port".to_string()),
             raw: "invalid".to_string(),
-            message: "invalid digit found in string".to_string(),
+            error: "invalid digit found in string".into(),
             env_var_name: "PORT".to_string(),
         }];
 
@@ -223,6 +274,39 @@ mod tests {
         assert!(result.contains("custom validation failed"));
     }
 
+    #[test]
+    fn test_source_error() {
+        let mut printer = TablePrinter::new();
+        let errors = vec![ConfigFieldError::SourceError {
+            origin: ".env".to_string(),
+            line: Some(7),
+            message: "expected KEY=VALUE".to_string(),
+        }];
+
+        let result = printer.print(&errors);
+
+        assert!(result.contains(".env:7"));
+        assert!(result.contains("expected KEY=VALUE"));
+    }
+
+    #[test]
+    fn test_file_error() {
+        let mut printer = TablePrinter::new();
+        let errors = vec![ConfigFieldError::FileError {
+            field_idx: 0,
+            field_name: Some("password".to_string()),
+            env_var_name: "DB_PASSWORD_FILE".to_string(),
+            path: "/run/secrets/db_password".to_string(),
+            message: "permission denied".to_string(),
+        }];
+
+        let result = printer.print(&errors);
+
+        assert!(result.contains("password"));
+        assert!(result.contains("DB_PASSWORD_FILE"));
+        assert!(result.contains("Failed to read '/run/secrets/db_password': permission denied"));
+    }
+
     #[test]
     fn test_nested_error() {
         let mut printer = TablePrinter::new();
@@ -230,7 +314,7 @@ mod tests {
             field_idx: 0,
             field_name: Some("host".to_string()),
             raw: "".to_string(),
-            message: "empty string not allowed".to_string(),
+            error: "empty string not allowed".into(),
             env_var_name: "DB_HOST".to_string(),
         }];
 
@@ -261,7 +345,7 @@ mod tests {
                 field_idx: 1,
                 field_name: Some("timeout".to_string()),
                 raw: "abc".to_string(),
-                message: "invalid digit found in string".to_string(),
+                error: "invalid digit found in string".into(),
                 env_var_name: "TIMEOUT".to_string(),
             },
             ConfigFieldError::Other {
@@ -290,7 +374,7 @@ mod tests {
             field_idx: 0,
             field_name: Some("pool_size".to_string()),
             raw: "not_a_number".to_string(),
-            message: "invalid digit found in string".to_string(),
+            error: "invalid digit found in string".into(),
             env_var_name: "POOL_SIZE".to_string(),
         }];
 
@@ -330,7 +414,7 @@ mod tests {
             field_idx: 2,
             field_name: None,
             raw: "invalid".to_string(),
-            message: "parse error".to_string(),
+            error: "parse error".into(),
             env_var_name: "FIELD_2".to_string(),
         }];
 
@@ -353,7 +437,7 @@ mod tests {
             field_idx: 0,
             field_name: Some("ttl".to_string()),
             raw: "forever".to_string(),
-            message: "invalid duration".to_string(),
+            error: "invalid duration".into(),
             env_var_name: "CACHE_TTL".to_string(),
         }];
 
@@ -441,4 +525,60 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_no_variant_loaded_error() {
+        let mut printer = TablePrinter::new();
+        let errors = vec![ConfigFieldError::NoVariantLoaded {
+            variant_errors: vec![
+                (
+                    "Kafka".to_string(),
+                    ConfigError {
+                        field_errors: vec![ConfigFieldError::MissingValue {
+                            field_name: Some("broker".to_string()),
+                            field_idx: 0,
+                            env_vars: vec!["KAFKA_BROKER".to_string()],
+                        }],
+                    },
+                ),
+                (
+                    "Pulsar".to_string(),
+                    ConfigError {
+                        field_errors: vec![ConfigFieldError::MissingValue {
+                            field_name: Some("broker".to_string()),
+                            field_idx: 0,
+                            env_vars: vec!["PULSAR_BROKER".to_string()],
+                        }],
+                    },
+                ),
+            ],
+        }];
+
+        let result = printer.print(&errors);
+
+        assert!(result.contains("KAFKA_BROKER"));
+        assert!(result.contains("Variant Kafka: Required variable not set"));
+        assert!(result.contains("PULSAR_BROKER"));
+        assert!(result.contains("Variant Pulsar: Required variable not set"));
+    }
+
+    #[test]
+    fn test_parsing_error_with_expected_format_and_hint() {
+        let mut printer = TablePrinter::new();
+        let errors = vec![ConfigFieldError::ParsingError {
+            field_idx: 0,
+            field_name: Some("port".to_string()),
+            raw: "http".to_string(),
+            error: DecodeError::new("invalid digit found in string")
+                .with_expected("an integer between 0 and 65535")
+                .with_hint("set the port number, like 8080"),
+            env_var_name: "PORT".to_string(),
+        }];
+
+        let result = printer.print(&errors);
+
+        assert!(result.contains(
+            "invalid digit found in string (raw value: 'http'); expected an integer between 0 and 65535; hint: set the port number, like 8080"
+        ));
+    }
 }
@@ -0,0 +1,282 @@
+use crate::config_field_error::ConfigFieldError;
+use crate::field_path::FieldPath;
+use std::path::PathBuf;
+
+/// A raw value found by a [`Source`], together with a label describing where it came from.
+///
+/// The origin label is meant for humans: it's what shows up when you need to find out
+/// which source provided a value (e.g. `"environment"` or `"override"`).
+///
+/// # Examples
+///
+/// ```rust
+/// use tryphon::SourceValue;
+///
+/// let value = SourceValue::new("8080", "environment");
+///
+/// assert_eq!(value.value, "8080");
+/// assert_eq!(value.origin, "environment");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceValue {
+    /// The raw string value, before it's decoded with [`ConfigValueDecoder`](crate::ConfigValueDecoder).
+    pub value: String,
+    /// A human-readable label describing where the value came from.
+    pub origin: String,
+    /// The file the whole value was read from, if any.
+    ///
+    /// Set for values read from a file per key, like [`Directory`](crate::Directory) entries or
+    /// files named by `*_FILE` variables, so parsing errors can point at the file.
+    pub path: Option<PathBuf>,
+}
+
+impl SourceValue {
+    /// Creates a new value with the given origin label.
+    pub fn new(value: impl Into<String>, origin: impl Into<String>) -> Self {
+        SourceValue {
+            value: value.into(),
+            origin: origin.into(),
+            path: None,
+        }
+    }
+
+    /// Creates a new value read from the file at `path`, labelled with the path as its origin.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use tryphon::SourceValue;
+    ///
+    /// let value = SourceValue::from_file("hunter2", "/run/secrets/db_password");
+    ///
+    /// assert_eq!(value.origin, "/run/secrets/db_password");
+    /// assert_eq!(value.path.as_deref(), Some(Path::new("/run/secrets/db_password")));
+    /// ```
+    pub fn from_file(value: impl Into<String>, path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+
+        SourceValue {
+            value: value.into(),
+            origin: path.display().to_string(),
+            path: Some(path),
+        }
+    }
+}
+
+/// A place configuration values can be read from.
+///
+/// Every `#[derive(Config)]` type can be loaded from any `Source` using
+/// [`Config::load_from`](crate::Config::load_from). The keys passed to [`lookup`](Source::lookup)
+/// are the names given in `#[env("...")]` attributes, tried in the order they're declared.
+/// If none of them is found, the field is looked up by its [`FieldPath`] with
+/// [`lookup_path`](Source::lookup_path), which is how structured sources such as TOML files
+/// map nested configurations to tables.
+///
+/// Built-in sources are listed in the [`sources`](crate::sources) module. Several sources can be
+/// combined with an explicit precedence using [`Layered`](crate::Layered).
+///
+/// # Examples
+///
+/// A fixture source backed by a `HashMap`:
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use tryphon::{Config, Source, SourceValue};
+///
+/// struct Fixture(HashMap<&'static str, &'static str>);
+///
+/// impl Source for Fixture {
+///     fn lookup(&self, key: &str) -> Result<Option<SourceValue>, String> {
+///         Ok(self.0.get(key).map(|value| SourceValue::new(*value, "fixture")))
+///     }
+/// }
+///
+/// #[derive(Config)]
+/// struct AppConfig {
+///     #[env("PORT")]
+///     port: u16,
+/// }
+///
+/// let fixture = Fixture(HashMap::from([("PORT", "9090")]));
+/// let config = AppConfig::load_from(&fixture).unwrap();
+///
+/// assert_eq!(config.port, 9090);
+/// ```
+pub trait Source {
+    /// Looks up a raw value by key.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Some(value))` if the key is present
+    /// - `Ok(None)` if the key is not present, so the next fallback (or default) should be used
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if the key is present but its value can't be read
+    /// (e.g. an environment variable that isn't valid Unicode).
+    fn lookup(&self, key: &str) -> Result<Option<SourceValue>, String>;
+
+    /// Looks up a raw value by the path of the field in the configuration, like `database.host`.
+    ///
+    /// Used for fields none of whose `#[env]` names were found by [`lookup`](Source::lookup).
+    /// Each segment of the path is the name of a field (or its index, for tuple structs), so
+    /// nested `#[config]` fields correspond to the leading segments. The default implementation
+    /// finds nothing, which suits flat, name-based sources like the environment.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if the path exists but doesn't hold a usable value, e.g. when
+    /// a nested table is found where a scalar value is expected.
+    fn lookup_path(&self, path: &FieldPath) -> Result<Option<SourceValue>, String> {
+        let _ = path;
+        Ok(None)
+    }
+
+    /// Looks up a raw value by the name given in a `#[credential("...")]` attribute.
+    ///
+    /// Credentials are secrets passed by a service manager, like systemd credentials, and are
+    /// tried before the `#[env]` names of a field. The default implementation finds nothing;
+    /// see [`Credentials`](crate::Credentials) for the systemd implementation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if the credential exists but can't be read.
+    fn lookup_credential(&self, name: &str) -> Result<Option<SourceValue>, String> {
+        let _ = name;
+        Ok(None)
+    }
+
+    /// Resolves the raw value of the field at `path`, declared with the `#[env]` names `env_vars`.
+    ///
+    /// Returns the value together with the key it was found under: the environment variable
+    /// name, or the dotted path of the field. The default implementation tries each of
+    /// `env_vars` with [`lookup`](Source::lookup), then the path with
+    /// [`lookup_path`](Source::lookup_path). Sources composed of other sources, like
+    /// [`Layered`](crate::Layered), override it so that a field is resolved completely against
+    /// one source before the next one is consulted.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error reported by [`lookup`](Source::lookup) or
+    /// [`lookup_path`](Source::lookup_path).
+    fn resolve(
+        &self,
+        env_vars: &[&str],
+        path: &FieldPath,
+    ) -> Result<Option<(SourceValue, String)>, String> {
+        for env_var_name in env_vars {
+            if let Some(value) = self.lookup(env_var_name)? {
+                return Ok(Some((value, env_var_name.to_string())));
+            }
+        }
+
+        Ok(self
+            .lookup_path(path)?
+            .map(|value| (value, path.dotted_path())))
+    }
+
+    /// Returns the keys of all values of this source.
+    ///
+    /// Used to discover the entries of `#[config(map = "...")]` maps, whose keys are part of
+    /// the names of their variables. The default implementation returns no keys, so sources
+    /// that can't enumerate their keys never contribute entries to such maps.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if the keys can't be listed.
+    fn keys(&self) -> Result<Vec<String>, String> {
+        Ok(Vec::new())
+    }
+
+    /// Returns the keys of the table at `path`, in sources of structured documents.
+    ///
+    /// Used to discover the entries of `#[config(map = "...")]` maps stored as tables, like
+    /// `[databases.orders]` in TOML for the map field `databases`. The default implementation
+    /// returns no keys.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if the value at `path` isn't a table.
+    fn keys_at(&self, path: &FieldPath) -> Result<Vec<String>, String> {
+        let _ = path;
+        Ok(Vec::new())
+    }
+
+    /// Returns errors found while preparing the source, such as syntax errors in a file.
+    ///
+    /// These errors aren't tied to a single field, so [`Config::load_from`](crate::Config::load_from)
+    /// reports them alongside the field errors, usually as
+    /// [`ConfigFieldError::SourceError`]. The default implementation returns no errors.
+    fn errors(&self) -> Vec<ConfigFieldError> {
+        Vec::new()
+    }
+}
+
+impl<S: Source + ?Sized> Source for &S {
+    fn lookup(&self, key: &str) -> Result<Option<SourceValue>, String> {
+        (**self).lookup(key)
+    }
+
+    fn lookup_credential(&self, name: &str) -> Result<Option<SourceValue>, String> {
+        (**self).lookup_credential(name)
+    }
+
+    fn lookup_path(&self, path: &FieldPath) -> Result<Option<SourceValue>, String> {
+        (**self).lookup_path(path)
+    }
+
+    fn resolve(
+        &self,
+        env_vars: &[&str],
+        path: &FieldPath,
+    ) -> Result<Option<(SourceValue, String)>, String> {
+        (**self).resolve(env_vars, path)
+    }
+
+    fn keys(&self) -> Result<Vec<String>, String> {
+        (**self).keys()
+    }
+
+    fn keys_at(&self, path: &FieldPath) -> Result<Vec<String>, String> {
+        (**self).keys_at(path)
+    }
+
+    fn errors(&self) -> Vec<ConfigFieldError> {
+        (**self).errors()
+    }
+}
+
+impl<S: Source + ?Sized> Source for Box<S> {
+    fn lookup(&self, key: &str) -> Result<Option<SourceValue>, String> {
+        (**self).lookup(key)
+    }
+
+    fn lookup_credential(&self, name: &str) -> Result<Option<SourceValue>, String> {
+        (**self).lookup_credential(name)
+    }
+
+    fn lookup_path(&self, path: &FieldPath) -> Result<Option<SourceValue>, String> {
+        (**self).lookup_path(path)
+    }
+
+    fn resolve(
+        &self,
+        env_vars: &[&str],
+        path: &FieldPath,
+    ) -> Result<Option<(SourceValue, String)>, String> {
+        (**self).resolve(env_vars, path)
+    }
+
+    fn keys(&self) -> Result<Vec<String>, String> {
+        (**self).keys()
+    }
+
+    fn keys_at(&self, path: &FieldPath) -> Result<Vec<String>, String> {
+        (**self).keys_at(path)
+    }
+
+    fn errors(&self) -> Vec<ConfigFieldError> {
+        (**self).errors()
+    }
+}
@@ -0,0 +1,277 @@
+use crate::config::Config;
+use crate::config_field_error::ConfigFieldError;
+use crate::field_info::FieldInfo;
+use crate::field_path::FieldPath;
+use crate::source::{Source, SourceValue};
+use std::collections::HashMap;
+
+/// Origin label of values read from command-line arguments.
+pub const ARGS_ORIGIN: &str = "command line";
+
+/// A [`Source`] reading values from command-line arguments.
+///
+/// Flags are derived from the paths of the fields of the configuration: `database.port`
+/// becomes `--database-port`, with underscores in field names also replaced by dashes. Any field
+/// can also be set by its dotted path with `--set database.port=5432`. Supported forms:
+///
+/// - `--database-port 5432` and `--database-port=5432`
+/// - `--set database.port=5432` and `--set=database.port=5432`
+/// - `--help` and `-h`, see [`help_requested`](Args::help_requested) and [`help`](Args::help)
+///
+/// When the same field is given more than once, the last value wins. Flags shared by several
+/// fields, like `--database-port` for both `database_port` and `database.port`, can only be set
+/// with `--set`. Unknown or shared flags, positional arguments and flags without a value are
+/// reported as [`ConfigFieldError::SourceError`] entries when a config is loaded from this
+/// source, so loading fails unless every argument sets a field. Arguments meant for the rest
+/// of the application must be removed before they are passed to [`parse`](Args::parse).
+///
+/// Fields are looked up by their path, so arguments take precedence over environment variables
+/// only when this source is layered above the environment, as done by
+/// [`Config::load_with_args`](crate::Config::load_with_args).
+///
+/// Values are labelled with the origin `"command line"`.
+///
+/// # Examples
+///
+/// ```rust
+/// use tryphon::{Args, Config, Env, EnvOverrides, Layered};
+///
+/// #[derive(Config)]
+/// struct DatabaseConfig {
+///     #[env("DB_HOST")]
+///     host: String,
+/// }
+///
+/// #[derive(Config)]
+/// struct AppConfig {
+///     #[env("PORT")]
+///     port: u16,
+///
+///     #[config]
+///     database: DatabaseConfig,
+/// }
+///
+/// let mut overrides = EnvOverrides::init();
+/// overrides.set("PORT", "8080").set("DB_HOST", "db1");
+///
+/// let args = Args::parse::<AppConfig>(["--port", "9090", "--set", "database.host=db2"]);
+/// let config = AppConfig::load_from(&Layered::new().layer(Env).layer(args)).unwrap();
+///
+/// assert_eq!(config.port, 9090);
+/// assert_eq!(config.database.host, "db2");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Args {
+    values: HashMap<String, SourceValue>,
+    help: bool,
+    errors: Vec<ConfigFieldError>,
+}
+
+impl Args {
+    /// Parses the arguments of the current process, skipping the program name.
+    pub fn from_command_line<C: Config>() -> Args {
+        Self::parse::<C>(std::env::args().skip(1))
+    }
+
+    /// Parses `args` for the fields of the configuration `C`. The program name must not be
+    /// included.
+    ///
+    /// Every argument that doesn't set a field, like an unknown flag, is recorded as an error
+    /// and fails the loading.
+    pub fn parse<C: Config>(args: impl IntoIterator<Item = impl Into<String>>) -> Args {
+        let fields = C::fields();
+
+        let mut paths = HashMap::<String, Vec<String>>::new();
+        for field in &fields {
+            paths
+                .entry(flag_name(&field.path))
+                .or_default()
+                .push(field.path.dotted_path());
+        }
+
+        let mut parsed = Args::default();
+        let mut args = args.into_iter().map(Into::into).peekable();
+
+        while let Some(arg) = args.next() {
+            if arg == "--help" || arg == "-h" {
+                parsed.help = true;
+                continue;
+            }
+
+            let Some(flag) = arg.strip_prefix("--") else {
+                parsed.error(format!("unexpected argument '{}'", arg));
+                continue;
+            };
+
+            let (flag, inline_value) = match flag.split_once('=') {
+                Some((flag, value)) => (flag, Some(value.to_string())),
+                None => (flag, None),
+            };
+
+            let is_set = flag == "set";
+
+            if !is_set && !paths.contains_key(flag) {
+                parsed.error(format!("unknown argument '--{}'", flag));
+                continue;
+            }
+
+            let value = match inline_value {
+                Some(value) => value,
+                None => match args.next_if(|next| !next.starts_with("--")) {
+                    Some(value) => value,
+                    None => {
+                        parsed.error(format!("missing value for '--{}'", flag));
+                        continue;
+                    }
+                },
+            };
+
+            if is_set {
+                match value.split_once('=') {
+                    Some((path, value))
+                        if fields.iter().any(|field| field.path.dotted_path() == path) =>
+                    {
+                        parsed.insert(path.to_string(), value.to_string());
+                    }
+                    Some((path, _)) => parsed.error(format!("unknown field '{}' in '--set'", path)),
+                    None => parsed.error(format!(
+                        "expected PATH=VALUE after '--set', got '{}'",
+                        value
+                    )),
+                }
+            } else {
+                match &paths[flag][..] {
+                    [path] => parsed.insert(path.clone(), value),
+                    ambiguous => parsed.error(format!(
+                        "ambiguous argument '--{}' matches fields {}, use '--set PATH=VALUE' instead",
+                        flag,
+                        ambiguous
+                            .iter()
+                            .map(|path| format!("'{}'", path))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )),
+                }
+            }
+        }
+
+        parsed
+    }
+
+    /// Returns `true` if `--help` or `-h` was passed.
+    pub fn help_requested(&self) -> bool {
+        self.help
+    }
+
+    /// Returns the help text for the configuration `C`, listing the flag of every field
+    /// together with its environment variables and default value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tryphon::{Args, Config};
+    ///
+    /// #[derive(Config)]
+    /// struct AppConfig {
+    ///     #[env("APP_PORT")]
+    ///     #[env("PORT")]
+    ///     #[default(8080)]
+    ///     port: u16,
+    /// }
+    ///
+    /// let help = Args::help::<AppConfig>();
+    ///
+    /// assert!(help.contains("--port <VALUE>"));
+    /// assert!(help.contains("env: APP_PORT, PORT; default: 8080"));
+    /// ```
+    pub fn help<C: Config>() -> String {
+        let mut rows = C::fields()
+            .iter()
+            .map(|field| {
+                (
+                    format!("--{} <VALUE>", flag_name(&field.path)),
+                    describe(field),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        rows.push((
+            "--set <PATH=VALUE>".to_string(),
+            "set any field by its path, e.g. database.port=5432".to_string(),
+        ));
+        rows.push(("-h, --help".to_string(), "print this help".to_string()));
+
+        let width = rows.iter().map(|(flag, _)| flag.len()).max().unwrap_or(0);
+
+        let mut help = String::from("Options:\n");
+        for (flag, description) in rows {
+            help.push_str(&format!(
+                "  {:<width$}  {}\n",
+                flag,
+                description,
+                width = width
+            ));
+        }
+        help
+    }
+
+    fn insert(&mut self, path: String, value: String) {
+        self.values
+            .insert(path, SourceValue::new(value, ARGS_ORIGIN));
+    }
+
+    fn error(&mut self, message: String) {
+        self.errors.push(ConfigFieldError::SourceError {
+            origin: ARGS_ORIGIN.to_string(),
+            line: None,
+            message,
+        });
+    }
+}
+
+impl Source for Args {
+    fn lookup(&self, _key: &str) -> Result<Option<SourceValue>, String> {
+        Ok(None)
+    }
+
+    fn lookup_path(&self, path: &FieldPath) -> Result<Option<SourceValue>, String> {
+        Ok(self.values.get(&path.dotted_path()).cloned())
+    }
+
+    fn errors(&self) -> Vec<ConfigFieldError> {
+        self.errors.clone()
+    }
+}
+
+/// Converts the path of a field to the name of its flag, without the leading dashes.
+fn flag_name(path: &FieldPath) -> String {
+    path.segments().join("-").replace('_', "-")
+}
+
+fn describe(field: &FieldInfo) -> String {
+    let mut parts = Vec::new();
+
+    if !field.env_vars.is_empty() {
+        parts.push(format!("env: {}", field.env_vars.join(", ")));
+    }
+
+    if let Some(default) = &field.default {
+        parts.push(format!("default: {}", default));
+    }
+
+    parts.join("; ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flag_name() {
+        let path = FieldPath::root()
+            .with_segment("database")
+            .with_segment("max_connections");
+
+        assert_eq!(flag_name(&path), "database-max-connections");
+    }
+}
@@ -0,0 +1,103 @@
+use crate::source::{Source, SourceValue};
+use crate::sources::{Directory, Env};
+use std::path::Path;
+
+/// Name of the environment variable systemd sets to the directory holding the credentials
+/// of a service.
+pub const CREDENTIALS_DIRECTORY_VAR: &str = "CREDENTIALS_DIRECTORY";
+
+/// A [`Source`] reading systemd credentials.
+///
+/// Services started with `LoadCredential=` or `SetCredential=` get their secrets as files in
+/// the directory named by `$CREDENTIALS_DIRECTORY`, instead of environment variables. Fields
+/// read credentials with the `#[credential("name")]` attribute, which is tried before their
+/// `#[env]` names, so environment variables act as a fallback when the service runs outside
+/// systemd. Credentials are never looked up by `#[env]` names.
+///
+/// [`Config::load`](crate::Config::load) and the other `load_with_*` methods layer
+/// [`Credentials::from_env`] over [`Env`]. Add it yourself when composing sources with
+/// [`Layered`](crate::Layered), or use [`Credentials::from_path`] to read credentials from a
+/// specific directory.
+///
+/// Credential files are read like the files of a [`Directory`], so one trailing newline is
+/// stripped and values are labelled with the path of the file. If the directory can't be
+/// read, the error is only reported for the fields with `#[credential]` names, so configs
+/// without credentials still load.
+///
+/// # Examples
+///
+/// ```rust no_run
+/// use tryphon::{Config, Secret};
+///
+/// #[derive(Config)]
+/// struct DbConfig {
+///     // LoadCredential=db-password:/etc/secrets/db-password
+///     #[credential("db-password")]
+///     #[env("DB_PASSWORD")]
+///     password: Secret<String>,
+/// }
+///
+/// let config = DbConfig::load().unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Credentials {
+    directory: Option<Directory>,
+    error: Option<String>,
+}
+
+impl Credentials {
+    /// Creates a source reading credentials from the directory named by `$CREDENTIALS_DIRECTORY`.
+    ///
+    /// The variable is read once, with [`Env`], so [`EnvOverrides`](crate::EnvOverrides) are
+    /// respected. If it isn't set, no credentials are found. If it can't be read, or the
+    /// directory doesn't exist, the error is reported when a credential is looked up.
+    pub fn from_env() -> Credentials {
+        match Env.lookup(CREDENTIALS_DIRECTORY_VAR) {
+            Ok(Some(value)) => Self::from_path(value.value),
+            Ok(None) => Credentials::default(),
+            Err(message) => Credentials {
+                directory: None,
+                error: Some(format!(
+                    "Failed to read ${}: {}",
+                    CREDENTIALS_DIRECTORY_VAR, message
+                )),
+            },
+        }
+    }
+
+    /// Creates a source reading credentials from the directory at `path`.
+    ///
+    /// If the directory doesn't exist, the error is reported when a credential is looked up.
+    pub fn from_path(path: impl AsRef<Path>) -> Credentials {
+        let directory = Directory::from_path(path.as_ref());
+        let error = directory.errors().first().map(|error| {
+            format!(
+                "Failed to read credentials directory '{}': {}",
+                path.as_ref().display(),
+                error.message().unwrap_or_default()
+            )
+        });
+
+        Credentials {
+            directory: Some(directory),
+            error,
+        }
+    }
+}
+
+impl Source for Credentials {
+    fn lookup(&self, _key: &str) -> Result<Option<SourceValue>, String> {
+        Ok(None)
+    }
+
+    fn lookup_credential(&self, name: &str) -> Result<Option<SourceValue>, String> {
+        if let Some(error) = &self.error {
+            return Err(error.clone());
+        }
+
+        match &self.directory {
+            Some(directory) => directory.lookup(name),
+            None => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,216 @@
+use crate::config_field_error::ConfigFieldError;
+use crate::source::{Source, SourceValue};
+use std::fs::File;
+use std::io::{ErrorKind, Read};
+use std::path::{Path, PathBuf};
+
+/// The default maximum size of a single file, 1 MiB, which is also the size limit of a
+/// Kubernetes ConfigMap or Secret.
+pub const DEFAULT_SIZE_LIMIT: u64 = 1024 * 1024;
+
+/// A [`Source`] reading values from a directory with one file per key.
+///
+/// This is the layout Kubernetes uses when mounting a ConfigMap or a Secret as a volume: every
+/// file name is a key, and the file body is the value. Files are looked up by the `#[env]` names
+/// of the fields, optionally transformed with [`map_keys`](Directory::map_keys). One trailing
+/// newline is stripped from every value.
+///
+/// Hidden entries (names starting with `.`) are never read, so the `..data` symlink and the
+/// timestamped `..2024_01_01_00_00_00.000000000` directories Kubernetes uses to update mounted
+/// volumes atomically are ignored, while the per-key symlinks pointing into them are followed.
+///
+/// Files larger than the size limit ([`DEFAULT_SIZE_LIMIT`] unless changed with
+/// [`with_size_limit`](Directory::with_size_limit)), or that can't be read, are reported as
+/// errors for the field. If the directory itself doesn't exist, that's reported as a
+/// [`ConfigFieldError::SourceError`].
+///
+/// Values are labelled with the path of the file they were read from, which is also included
+/// in the message of [`ConfigFieldError::ParsingError`] when a value can't be decoded.
+///
+/// # Examples
+///
+/// ```rust no_run
+/// use tryphon::{Config, Directory};
+///
+/// #[derive(Config)]
+/// struct DbConfig {
+///     #[env("DB_HOST")]
+///     host: String,
+///
+///     #[env("DB_PASSWORD")]
+///     password: String,
+/// }
+///
+/// // /etc/config/DB_HOST contains "db.internal\n"
+/// // /etc/config/DB_PASSWORD contains "hunter2\n"
+/// let config = DbConfig::load_from(&Directory::from_path("/etc/config")).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct Directory {
+    path: PathBuf,
+    size_limit: u64,
+    key_mapper: fn(&str) -> String,
+    errors: Vec<ConfigFieldError>,
+}
+
+impl Directory {
+    /// Creates a source reading files from the directory at `path`.
+    ///
+    /// Files are read when values are looked up. If the directory doesn't exist, the error is
+    /// recorded and reported when a config is loaded from this source.
+    pub fn from_path(path: impl AsRef<Path>) -> Directory {
+        let path = path.as_ref().to_path_buf();
+
+        let errors = match std::fs::metadata(&path) {
+            Ok(metadata) if metadata.is_dir() => Vec::new(),
+            Ok(_) => vec![ConfigFieldError::SourceError {
+                origin: path.display().to_string(),
+                line: None,
+                message: "not a directory".to_string(),
+            }],
+            Err(e) => vec![ConfigFieldError::SourceError {
+                origin: path.display().to_string(),
+                line: None,
+                message: e.to_string(),
+            }],
+        };
+
+        Directory {
+            path,
+            size_limit: DEFAULT_SIZE_LIMIT,
+            key_mapper: |key| key.to_string(),
+            errors,
+        }
+    }
+
+    /// Sets the maximum size of a single file, in bytes.
+    pub fn with_size_limit(mut self, size_limit: u64) -> Directory {
+        self.size_limit = size_limit;
+        self
+    }
+
+    /// Sets the function mapping the names used in `#[env]` attributes to file names.
+    ///
+    /// By default, the file name is the same as the `#[env]` name.
+    ///
+    /// # Example
+    ///
+    /// ```rust no_run
+    /// use tryphon::Directory;
+    ///
+    /// // DB_PASSWORD is read from /etc/config/db_password
+    /// let source = Directory::from_path("/etc/config").map_keys(|key| key.to_lowercase());
+    /// ```
+    pub fn map_keys(mut self, key_mapper: fn(&str) -> String) -> Directory {
+        self.key_mapper = key_mapper;
+        self
+    }
+}
+
+impl Source for Directory {
+    fn lookup(&self, key: &str) -> Result<Option<SourceValue>, String> {
+        let file_name = (self.key_mapper)(key);
+
+        if file_name.is_empty() || file_name.starts_with('.') || file_name.contains(['/', '\\']) {
+            return Ok(None);
+        }
+
+        let path = self.path.join(file_name);
+
+        let metadata = match std::fs::metadata(&path) {
+            Ok(metadata) => metadata,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(format!("Failed to read '{}': {}", path.display(), e)),
+        };
+
+        if !metadata.is_file() {
+            return Ok(None);
+        }
+
+        if metadata.len() > self.size_limit {
+            return Err(format!(
+                "File '{}' has {} bytes, more than the limit of {} bytes",
+                path.display(),
+                metadata.len(),
+                self.size_limit
+            ));
+        }
+
+        match read_value_file(&path, self.size_limit) {
+            Ok(contents) => Ok(Some(SourceValue::from_file(contents, path))),
+            Err(e) => Err(format!("Failed to read '{}': {}", path.display(), e)),
+        }
+    }
+
+    /// Returns the names of the files in the directory, skipping hidden entries. With
+    /// [`map_keys`](Directory::map_keys), file names only find entries of maps if the mapping
+    /// leaves them unchanged.
+    fn keys(&self) -> Result<Vec<String>, String> {
+        // A missing directory is already reported by `errors`.
+        let Ok(entries) = std::fs::read_dir(&self.path) else {
+            return Ok(Vec::new());
+        };
+
+        let mut keys = Vec::new();
+
+        for entry in entries {
+            let entry =
+                entry.map_err(|e| format!("Failed to list '{}': {}", self.path.display(), e))?;
+
+            if let Ok(name) = entry.file_name().into_string()
+                && !name.starts_with('.')
+                && entry.path().is_file()
+            {
+                keys.push(name);
+            }
+        }
+
+        Ok(keys)
+    }
+
+    fn errors(&self) -> Vec<ConfigFieldError> {
+        self.errors.clone()
+    }
+}
+
+/// Reads a file with a single value, stripping one trailing newline. The size limit is checked
+/// while reading, so it also holds for files without a known size, like `/dev/zero`.
+pub(crate) fn read_value_file(path: &Path, size_limit: u64) -> std::io::Result<String> {
+    let mut contents = String::new();
+    File::open(path)?
+        .take(size_limit.saturating_add(1))
+        .read_to_string(&mut contents)?;
+
+    if contents.len() as u64 > size_limit {
+        return Err(std::io::Error::new(
+            ErrorKind::FileTooLarge,
+            format!("more than the limit of {} bytes", size_limit),
+        ));
+    }
+
+    Ok(trim_trailing_newline(contents))
+}
+
+/// Strips one trailing newline (`\n` or `\r\n`) from the contents of a file.
+fn trim_trailing_newline(mut contents: String) -> String {
+    if contents.ends_with('\n') {
+        contents.pop();
+        if contents.ends_with('\r') {
+            contents.pop();
+        }
+    }
+    contents
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trim_trailing_newline() {
+        assert_eq!(trim_trailing_newline("value\n".to_string()), "value");
+        assert_eq!(trim_trailing_newline("value\r\n".to_string()), "value");
+        assert_eq!(trim_trailing_newline("value\n\n".to_string()), "value\n");
+        assert_eq!(trim_trailing_newline("value".to_string()), "value");
+    }
+}
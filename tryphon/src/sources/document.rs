@@ -0,0 +1,172 @@
+use crate::decoders::join_quoted;
+use crate::field_path::FieldPath;
+use crate::source::SourceValue;
+
+/// The kinds of values of a structured document, as seen by [`lookup_path`].
+pub(crate) enum Node<'a, V: DocumentValue> {
+    /// A value treated as missing, like JSON `null`.
+    #[cfg_attr(not(any(feature = "json", feature = "yaml")), allow(dead_code))]
+    Null,
+    /// A plain value, in the textual form passed to the decoder.
+    Scalar(String),
+    /// An array of values.
+    Array(&'a [V]),
+    /// A table of named values.
+    Nested(&'a V::Nested),
+    /// Any other value, which can't be read, like a YAML alias.
+    #[cfg_attr(not(feature = "yaml"), allow(dead_code))]
+    Other,
+}
+
+/// A value of a structured document, like a TOML, JSON or YAML value.
+pub(crate) trait DocumentValue: Sized {
+    /// The type of the tables of the document.
+    type Nested;
+
+    /// The name of the tables of the document, with an article, like `a table`.
+    const NESTED_NAME: &'static str;
+
+    /// Returns the kind of the value.
+    fn node(&self) -> Node<'_, Self>;
+
+    /// Returns the value named `key` in a table.
+    fn get<'a>(nested: &'a Self::Nested, key: &str) -> Option<&'a Self>;
+
+    /// Returns the names of the values of a table.
+    fn keys(nested: &Self::Nested) -> Vec<String>;
+
+    /// Returns the name of the type of the value, for errors.
+    fn type_name(&self) -> &'static str;
+}
+
+/// Looks up the value at `path` in a document, starting from its `root` table.
+///
+/// Index segments, like `[1]`, select elements of arrays, so `#[config(indexed)]` lists can be
+/// read from arrays of tables. Key segments, like `[ORDERS]`, select values of tables. An array
+/// of plain values is joined with `,`, quoting elements where needed, so it can be decoded into
+/// a collection with the default separator.
+pub(crate) fn lookup_path<V: DocumentValue>(
+    root: &V::Nested,
+    path: &FieldPath,
+    origin: &str,
+) -> Result<Option<SourceValue>, String> {
+    let Some(value) = find::<V>(root, path, origin)? else {
+        return Ok(None);
+    };
+
+    let expected_value = |value: &V, path: &FieldPath| {
+        format!(
+            "expected a value at '{}' in {}, found {}",
+            path,
+            origin,
+            value.type_name()
+        )
+    };
+
+    match value.node() {
+        Node::Null => Ok(None),
+        Node::Scalar(raw) => Ok(Some(SourceValue::new(raw, origin.to_string()))),
+        Node::Array(elements) => {
+            let elements = elements
+                .iter()
+                .enumerate()
+                .map(|(idx, element)| match element.node() {
+                    Node::Scalar(raw) => Ok(raw),
+                    _ => Err(expected_value(element, &path.with_index(idx))),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok(Some(SourceValue::new(
+                join_quoted(&elements),
+                origin.to_string(),
+            )))
+        }
+        Node::Nested(_) | Node::Other => Err(expected_value(value, path)),
+    }
+}
+
+/// Returns the names of the values of the table at `path` in a document, used to discover the
+/// keys of `#[config(map = "...")]` maps. A missing table has no keys.
+pub(crate) fn keys_at<V: DocumentValue>(
+    root: &V::Nested,
+    path: &FieldPath,
+    origin: &str,
+) -> Result<Vec<String>, String> {
+    let Some(value) = find::<V>(root, path, origin)? else {
+        return Ok(Vec::new());
+    };
+
+    match value.node() {
+        Node::Null => Ok(Vec::new()),
+        Node::Nested(nested) => Ok(V::keys(nested)),
+        _ => Err(format!(
+            "expected {} at '{}' in {}, found {}",
+            V::NESTED_NAME,
+            path,
+            origin,
+            value.type_name()
+        )),
+    }
+}
+
+/// Walks the segments of `path` from the `root` table, returning the value it points to.
+fn find<'a, V: DocumentValue>(
+    root: &'a V::Nested,
+    path: &FieldPath,
+    origin: &str,
+) -> Result<Option<&'a V>, String> {
+    let Some((first, rest)) = path.segments().split_first() else {
+        return Ok(None);
+    };
+
+    let Some(mut value) = V::get(root, key(first)) else {
+        return Ok(None);
+    };
+    let mut value_path = FieldPath::root().with_segment(first);
+
+    for segment in rest {
+        let expected_nested = |value: &V| {
+            format!(
+                "expected {} at '{}' in {}, found {}",
+                V::NESTED_NAME,
+                value_path,
+                origin,
+                value.type_name()
+            )
+        };
+
+        let child = match value.node() {
+            Node::Null => return Ok(None),
+            Node::Nested(nested) => V::get(nested, key(segment)),
+            Node::Array(elements) => match index(segment) {
+                Some(idx) => elements.get(idx),
+                None => return Err(expected_nested(value)),
+            },
+            Node::Scalar(_) | Node::Other => return Err(expected_nested(value)),
+        };
+
+        match child {
+            Some(child) => value = child,
+            None => return Ok(None),
+        }
+        value_path = value_path.with_segment(segment);
+    }
+
+    Ok(Some(value))
+}
+
+/// Returns the key of a table selected by a segment, like `ORDERS` for `[ORDERS]`.
+fn key(segment: &str) -> &str {
+    segment
+        .strip_prefix('[')
+        .and_then(|key| key.strip_suffix(']'))
+        .unwrap_or(segment)
+}
+
+/// Returns the index of an array element selected by a segment, like `1` for `[1]`.
+fn index(segment: &str) -> Option<usize> {
+    segment
+        .strip_prefix('[')
+        .and_then(|idx| idx.strip_suffix(']'))
+        .and_then(|idx| idx.parse().ok())
+}
@@ -0,0 +1,389 @@
+use crate::config_field_error::ConfigFieldError;
+use crate::source::{Source, SourceValue};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// A [`Source`] reading variables from one or more `.env` files.
+///
+/// Supported syntax:
+///
+/// - `KEY=value` pairs, with optional whitespace around `=`
+/// - an optional `export` prefix (`export KEY=value`)
+/// - full-line comments (`# comment`) and inline comments after unquoted values (`KEY=value # comment`)
+/// - single-quoted values, taken literally (`KEY='no $escapes\n here'`)
+/// - double-quoted values with escape sequences `\n`, `\r`, `\t`, `\\`, `\"`, `\'` and `\$`
+/// - multiline values inside single or double quotes
+///
+/// When the same key is defined more than once, the last definition wins. This also applies
+/// across files: with [`DotEnv::from_paths`], later files override earlier ones.
+///
+/// Syntax errors and unreadable files don't stop parsing; they're collected and reported as
+/// [`ConfigFieldError::SourceError`] entries (with the file and line number) next to the field
+/// errors when a config is loaded from this source.
+///
+/// Each value is labelled with an origin of the form `path:line`.
+///
+/// # Examples
+///
+/// ```rust
+/// use tryphon::{Config, DotEnv};
+///
+/// #[derive(Config)]
+/// struct AppConfig {
+///     #[env("DATABASE_URL")]
+///     database_url: String,
+///
+///     #[env("GREETING")]
+///     greeting: String,
+/// }
+///
+/// let dotenv = DotEnv::parse(
+///     r#"
+/// ## local development settings
+/// export DATABASE_URL=postgres://localhost/dev  # inline comment
+/// GREETING="Hello,\nWorld!"
+/// "#,
+///     ".env",
+/// );
+///
+/// let config = AppConfig::load_from(&dotenv).unwrap();
+///
+/// assert_eq!(config.database_url, "postgres://localhost/dev");
+/// assert_eq!(config.greeting, "Hello,\nWorld!");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DotEnv {
+    values: HashMap<String, SourceValue>,
+    errors: Vec<ConfigFieldError>,
+}
+
+impl DotEnv {
+    /// Reads and parses a single `.env` file.
+    ///
+    /// If the file can't be read, the I/O error is recorded and reported when a config is
+    /// loaded from this source.
+    pub fn from_path(path: impl AsRef<Path>) -> DotEnv {
+        Self::from_paths([path])
+    }
+
+    /// Reads and parses several `.env` files. Values from later files override values
+    /// from earlier ones.
+    ///
+    /// # Example
+    ///
+    /// ```rust no_run
+    /// use tryphon::DotEnv;
+    ///
+    /// let dotenv = DotEnv::from_paths([".env", ".env.local"]);
+    /// ```
+    pub fn from_paths<P: AsRef<Path>>(paths: impl IntoIterator<Item = P>) -> DotEnv {
+        let mut dotenv = DotEnv::default();
+
+        for path in paths {
+            let path = path.as_ref();
+            let origin = path.display().to_string();
+
+            match std::fs::read_to_string(path) {
+                Ok(contents) => dotenv.parse_into(&contents, &origin),
+                Err(e) => dotenv.errors.push(ConfigFieldError::SourceError {
+                    origin,
+                    line: None,
+                    message: e.to_string(),
+                }),
+            }
+        }
+
+        dotenv
+    }
+
+    /// Parses `.env` formatted contents. The `origin` is used to label values and errors,
+    /// usually it's the name of the file the contents come from.
+    pub fn parse(contents: &str, origin: &str) -> DotEnv {
+        let mut dotenv = DotEnv::default();
+        dotenv.parse_into(contents, origin);
+        dotenv
+    }
+
+    /// Returns the parsed value of a variable, if it's defined.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(|v| v.value.as_str())
+    }
+
+    fn parse_into(&mut self, contents: &str, origin: &str) {
+        let mut error = |line: usize, message: String| {
+            self.errors.push(ConfigFieldError::SourceError {
+                origin: origin.to_string(),
+                line: Some(line),
+                message,
+            })
+        };
+
+        let mut parsed = Vec::new();
+        let lines = contents.lines().collect::<Vec<_>>();
+        let mut idx = 0;
+
+        while idx < lines.len() {
+            let line_number = idx + 1;
+            let line = lines[idx].trim_start();
+            idx += 1;
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let line = line
+                .strip_prefix("export")
+                .filter(|rest| rest.starts_with(char::is_whitespace))
+                .map(str::trim_start)
+                .unwrap_or(line);
+
+            let Some((key, raw_value)) = line.split_once('=') else {
+                error(line_number, "expected KEY=VALUE".to_string());
+                continue;
+            };
+
+            let key = key.trim();
+
+            if !is_valid_key(key) {
+                error(line_number, format!("invalid variable name '{}'", key));
+                continue;
+            }
+
+            let trimmed_value = raw_value.trim_start();
+
+            let value = match trimmed_value.chars().next() {
+                Some(quote @ ('\'' | '"')) => {
+                    let mut text = trimmed_value[1..].to_string();
+                    loop {
+                        match find_closing_quote(&text, quote) {
+                            Some((value, rest)) => {
+                                let rest = rest.trim_start();
+                                if rest.is_empty() || rest.starts_with('#') {
+                                    break Ok(value);
+                                } else {
+                                    break Err(format!(
+                                        "unexpected characters after closing quote: '{}'",
+                                        rest
+                                    ));
+                                }
+                            }
+                            None if idx < lines.len() => {
+                                text.push('\n');
+                                text.push_str(lines[idx]);
+                                idx += 1;
+                            }
+                            None => {
+                                let kind = if quote == '"' { "double" } else { "single" };
+                                break Err(format!("unterminated {} quote", kind));
+                            }
+                        }
+                    }
+                }
+                _ => Ok(strip_inline_comment(raw_value).trim().to_string()),
+            };
+
+            match value {
+                Ok(value) => parsed.push((key.to_string(), value, line_number)),
+                Err(message) => error(line_number, message),
+            }
+        }
+
+        for (key, value, line_number) in parsed {
+            let origin = format!("{}:{}", origin, line_number);
+            self.values.insert(key, SourceValue::new(value, origin));
+        }
+    }
+}
+
+fn is_valid_key(key: &str) -> bool {
+    let mut chars = key.chars();
+
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+}
+
+/// Cuts an unquoted value at the first `#` preceded by whitespace.
+fn strip_inline_comment(raw: &str) -> &str {
+    let mut previous_is_whitespace = false;
+
+    for (idx, c) in raw.char_indices() {
+        if c == '#' && previous_is_whitespace {
+            return &raw[..idx];
+        }
+        previous_is_whitespace = c.is_whitespace();
+    }
+
+    raw
+}
+
+/// Finds the closing quote in `text` (which starts right after the opening quote).
+///
+/// Returns the unquoted value and the remaining text after the closing quote, or `None`
+/// if the quote isn't closed yet.
+fn find_closing_quote(text: &str, quote: char) -> Option<(String, &str)> {
+    if quote == '\'' {
+        return text
+            .find('\'')
+            .map(|idx| (text[..idx].to_string(), &text[idx + 1..]));
+    }
+
+    let mut value = String::new();
+    let mut chars = text.char_indices();
+
+    while let Some((idx, c)) = chars.next() {
+        match c {
+            '"' => return Some((value, &text[idx + 1..])),
+            '\\' => match chars.next() {
+                Some((_, 'n')) => value.push('\n'),
+                Some((_, 'r')) => value.push('\r'),
+                Some((_, 't')) => value.push('\t'),
+                Some((_, escaped @ ('\\' | '"' | '\'' | '$'))) => value.push(escaped),
+                Some((_, other)) => {
+                    value.push('\\');
+                    value.push(other);
+                }
+                None => value.push('\\'),
+            },
+            other => value.push(other),
+        }
+    }
+
+    None
+}
+
+impl Source for DotEnv {
+    fn lookup(&self, key: &str) -> Result<Option<SourceValue>, String> {
+        Ok(self.values.get(key).cloned())
+    }
+
+    fn keys(&self) -> Result<Vec<String>, String> {
+        Ok(self.values.keys().cloned().collect())
+    }
+
+    fn errors(&self) -> Vec<ConfigFieldError> {
+        self.errors.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error_lines(dotenv: &DotEnv) -> Vec<(usize, String)> {
+        dotenv
+            .errors
+            .iter()
+            .filter_map(|e| match e {
+                ConfigFieldError::SourceError {
+                    line: Some(line),
+                    message,
+                    ..
+                } => Some((*line, message.clone())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_simple_values() {
+        let dotenv = DotEnv::parse("FOO=bar\nBAZ = qux  \nEMPTY=\n", ".env");
+
+        assert_eq!(dotenv.get("FOO"), Some("bar"));
+        assert_eq!(dotenv.get("BAZ"), Some("qux"));
+        assert_eq!(dotenv.get("EMPTY"), Some(""));
+        assert!(dotenv.errors.is_empty());
+    }
+
+    #[test]
+    fn test_export_prefix_and_comments() {
+        let dotenv = DotEnv::parse(
+            "# comment\n  # indented comment\nexport FOO=bar # inline\nexported=value\nHASH=a#b\n",
+            ".env",
+        );
+
+        assert_eq!(dotenv.get("FOO"), Some("bar"));
+        assert_eq!(dotenv.get("exported"), Some("value"));
+        assert_eq!(dotenv.get("HASH"), Some("a#b"));
+        assert!(dotenv.errors.is_empty());
+    }
+
+    #[test]
+    fn test_quoted_values() {
+        let dotenv = DotEnv::parse(
+            r#"SINGLE='literal \n $HOME # not a comment'
+DOUBLE="tab\tnew\nline \"quoted\" \$HOME" # comment
+UNKNOWN="keep \q"
+"#,
+            ".env",
+        );
+
+        assert_eq!(
+            dotenv.get("SINGLE"),
+            Some(r"literal \n $HOME # not a comment")
+        );
+        assert_eq!(
+            dotenv.get("DOUBLE"),
+            Some("tab\tnew\nline \"quoted\" $HOME")
+        );
+        assert_eq!(dotenv.get("UNKNOWN"), Some(r"keep \q"));
+        assert!(dotenv.errors.is_empty());
+    }
+
+    #[test]
+    fn test_multiline_values() {
+        let dotenv = DotEnv::parse(
+            "CERT=\"-----BEGIN-----\nabc\n-----END-----\"\nNEXT='one\ntwo'\nLAST=1\n",
+            ".env",
+        );
+
+        assert_eq!(
+            dotenv.get("CERT"),
+            Some("-----BEGIN-----\nabc\n-----END-----")
+        );
+        assert_eq!(dotenv.get("NEXT"), Some("one\ntwo"));
+        assert_eq!(dotenv.get("LAST"), Some("1"));
+        assert_eq!(
+            dotenv.values.get("LAST").map(|v| v.origin.as_str()),
+            Some(".env:6")
+        );
+    }
+
+    #[test]
+    fn test_last_definition_wins() {
+        let dotenv = DotEnv::parse("FOO=first\nFOO=second\n", ".env");
+
+        assert_eq!(dotenv.get("FOO"), Some("second"));
+    }
+
+    #[test]
+    fn test_errors_with_line_numbers() {
+        let dotenv = DotEnv::parse(
+            "GOOD=1\nNO_EQUALS\n1BAD=x\nTRAILING=\"x\" y\nOPEN='never closed\nMORE=2\n",
+            ".env",
+        );
+
+        assert_eq!(
+            error_lines(&dotenv),
+            vec![
+                (2, "expected KEY=VALUE".to_string()),
+                (3, "invalid variable name '1BAD'".to_string()),
+                (
+                    4,
+                    "unexpected characters after closing quote: 'y'".to_string()
+                ),
+                (5, "unterminated single quote".to_string()),
+            ]
+        );
+        assert_eq!(dotenv.get("GOOD"), Some("1"));
+    }
+
+    #[test]
+    fn test_missing_file_is_reported() {
+        let dotenv = DotEnv::from_path("/definitely/not/here/.env");
+
+        assert!(matches!(
+            &dotenv.errors[..],
+            [ConfigFieldError::SourceError { origin, line: None, .. }] if origin == "/definitely/not/here/.env"
+        ));
+    }
+}
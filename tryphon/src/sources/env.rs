@@ -0,0 +1,65 @@
+use crate::env_overrides::EnvOverrides;
+use crate::source::{Source, SourceValue};
+
+/// Origin label of values read from the process environment.
+pub const ENV_ORIGIN: &str = "environment";
+
+/// Origin label of values read from thread-local [`EnvOverrides`].
+pub const OVERRIDE_ORIGIN: &str = "override";
+
+/// A [`Source`] reading from the process environment.
+///
+/// This is the source used by [`Config::load`](crate::Config::load). It behaves exactly like
+/// [`read_env`](crate::read_env): if [`EnvOverrides`] are initialized for the current thread,
+/// only the overrides are consulted; otherwise values are read with [`std::env::var`].
+///
+/// Values are labelled with the origin `"environment"` or `"override"`.
+///
+/// Names given in `#[credential]` attributes aren't read by this source. [`Config::load`](crate::Config::load)
+/// and the other `load_with_*` methods layer [`Credentials::from_env`](crate::Credentials::from_env)
+/// over it, which resolves the credentials directory once per load; add it to a
+/// [`Layered`](crate::Layered) stack to read credentials there too.
+///
+/// # Examples
+///
+/// ```rust
+/// use tryphon::{Config, Env};
+///
+/// #[derive(Config)]
+/// struct AppConfig {
+///     #[env("HOME_DIR")]
+///     #[default("/home")]
+///     home: String,
+/// }
+///
+/// let config = AppConfig::load_from(&Env).unwrap();
+/// # assert_eq!(config.home, "/home");
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Env;
+
+impl Source for Env {
+    fn lookup(&self, key: &str) -> Result<Option<SourceValue>, String> {
+        if EnvOverrides::is_initialized() {
+            return Ok(EnvOverrides::get(key).map(|value| SourceValue::new(value, OVERRIDE_ORIGIN)));
+        }
+
+        match std::env::var(key) {
+            Ok(value) => Ok(Some(SourceValue::new(value, ENV_ORIGIN))),
+            Err(std::env::VarError::NotPresent) => Ok(None),
+            Err(e @ std::env::VarError::NotUnicode(_)) => Err(e.to_string()),
+        }
+    }
+
+    /// Returns the names of all variables of the process environment, or of the overrides if
+    /// they are initialized. Names that aren't valid Unicode are skipped.
+    fn keys(&self) -> Result<Vec<String>, String> {
+        if EnvOverrides::is_initialized() {
+            return Ok(EnvOverrides::keys());
+        }
+
+        Ok(std::env::vars_os()
+            .filter_map(|(key, _)| key.into_string().ok())
+            .collect())
+    }
+}
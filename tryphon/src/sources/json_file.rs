@@ -0,0 +1,254 @@
+use crate::config_field_error::ConfigFieldError;
+use crate::field_path::FieldPath;
+use crate::source::{Source, SourceValue};
+use crate::sources::document::{self, DocumentValue, Node};
+use serde_json::{Map, Value};
+use std::path::Path;
+
+/// A [`Source`] reading values from a JSON document.
+///
+/// Works like [`Toml`](crate::Toml): fields are looked up by their [`FieldPath`], nested
+/// `#[config]` structs map to objects named after the field, and `#[env]` fields map to keys
+/// named after the field. Strings, numbers and booleans are passed to
+/// [`ConfigValueDecoder`](crate::ConfigValueDecoder) in their textual form, so custom decoders
+/// work the same as for environment variables. `null` is treated as a missing value.
+///
+/// Values are labelled with the origin they were created with, usually the path of the file.
+///
+/// Requires the `json` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use tryphon::{Config, ConfigValueDecoder, Json};
+///
+/// #[derive(Debug, PartialEq, ConfigValueDecoder)]
+/// enum LogLevel {
+///     Info,
+///     Debug,
+/// }
+///
+/// #[derive(Config)]
+/// struct LoggingConfig {
+///     #[env("LOG_LEVEL")]
+///     level: LogLevel,
+/// }
+///
+/// #[derive(Config)]
+/// struct AppConfig {
+///     #[env("PORT")]
+///     port: u16,
+///
+///     #[config]
+///     logging: LoggingConfig,
+/// }
+///
+/// let json = Json::parse(r#"{"port": 8080, "logging": {"level": "debug"}}"#, "config.json");
+/// let config = AppConfig::load_from(&json).unwrap();
+///
+/// assert_eq!(config.port, 8080);
+/// assert_eq!(config.logging.level, LogLevel::Debug);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Json {
+    object: Map<String, Value>,
+    origin: String,
+    errors: Vec<ConfigFieldError>,
+}
+
+impl Json {
+    /// Reads and parses a JSON file.
+    ///
+    /// If the file can't be read or parsed, the error is recorded and reported when a config
+    /// is loaded from this source.
+    pub fn from_path(path: impl AsRef<Path>) -> Json {
+        let path = path.as_ref();
+        let origin = path.display().to_string();
+
+        match std::fs::read_to_string(path) {
+            Ok(contents) => Self::parse(&contents, &origin),
+            Err(e) => Json::failed(&origin, None, e.to_string()),
+        }
+    }
+
+    /// Parses a JSON document, which must be an object. The `origin` is used to label values
+    /// and errors, usually it's the name of the file the contents come from.
+    pub fn parse(contents: &str, origin: &str) -> Json {
+        match serde_json::from_str::<Value>(contents) {
+            Ok(Value::Object(object)) => Json {
+                object,
+                origin: origin.to_string(),
+                errors: Vec::new(),
+            },
+            Ok(other) => Json::failed(
+                origin,
+                None,
+                format!(
+                    "expected an object at the root, found {}",
+                    type_name(&other)
+                ),
+            ),
+            Err(e) => {
+                let message = e.to_string();
+                let location = format!(" at line {} column {}", e.line(), e.column());
+                let message = message.strip_suffix(&location).unwrap_or(&message);
+
+                Json::failed(origin, Some(e.line()), message.to_string())
+            }
+        }
+    }
+
+    fn failed(origin: &str, line: Option<usize>, message: String) -> Json {
+        Json {
+            origin: origin.to_string(),
+            errors: vec![ConfigFieldError::SourceError {
+                origin: origin.to_string(),
+                line,
+                message,
+            }],
+            ..Json::default()
+        }
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+impl DocumentValue for Value {
+    type Nested = Map<String, Value>;
+
+    const NESTED_NAME: &'static str = "an object";
+
+    fn node(&self) -> Node<'_, Value> {
+        match self {
+            Value::Null => Node::Null,
+            Value::String(s) => Node::Scalar(s.clone()),
+            Value::Number(n) => Node::Scalar(n.to_string()),
+            Value::Bool(b) => Node::Scalar(b.to_string()),
+            Value::Array(elements) => Node::Array(elements),
+            Value::Object(object) => Node::Nested(object),
+        }
+    }
+
+    fn get<'a>(object: &'a Map<String, Value>, key: &str) -> Option<&'a Value> {
+        object.get(key)
+    }
+
+    fn keys(object: &Map<String, Value>) -> Vec<String> {
+        object.keys().cloned().collect()
+    }
+
+    fn type_name(&self) -> &'static str {
+        type_name(self)
+    }
+}
+
+impl Source for Json {
+    fn lookup(&self, _key: &str) -> Result<Option<SourceValue>, String> {
+        Ok(None)
+    }
+
+    fn lookup_path(&self, path: &FieldPath) -> Result<Option<SourceValue>, String> {
+        document::lookup_path::<Value>(&self.object, path, &self.origin)
+    }
+
+    fn keys_at(&self, path: &FieldPath) -> Result<Vec<String>, String> {
+        document::keys_at::<Value>(&self.object, path, &self.origin)
+    }
+
+    fn errors(&self) -> Vec<ConfigFieldError> {
+        self.errors.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lookup(json: &Json, path: &str) -> Result<Option<String>, String> {
+        json.lookup_path(&path.parse().unwrap())
+            .map(|v| v.map(|v| v.value))
+    }
+
+    #[test]
+    fn test_values() {
+        let json = Json::parse(
+            r#"{"name": "app", "port": 8080, "ratio": 0.5, "debug": true, "unset": null,
+                "database": {"host": "db", "pool": {"size": 4}}}"#,
+            "config.json",
+        );
+
+        assert_eq!(lookup(&json, "name"), Ok(Some("app".to_string())));
+        assert_eq!(lookup(&json, "port"), Ok(Some("8080".to_string())));
+        assert_eq!(lookup(&json, "ratio"), Ok(Some("0.5".to_string())));
+        assert_eq!(lookup(&json, "debug"), Ok(Some("true".to_string())));
+        assert_eq!(lookup(&json, "unset"), Ok(None));
+        assert_eq!(lookup(&json, "database.host"), Ok(Some("db".to_string())));
+        assert_eq!(
+            lookup(&json, "database.pool.size"),
+            Ok(Some("4".to_string()))
+        );
+        assert_eq!(lookup(&json, "cache.host"), Ok(None));
+    }
+
+    #[test]
+    fn test_arrays() {
+        let json = Json::parse(
+            r#"{"hosts": ["a", "b,c", 3], "upstreams": [{"host": "one"}, {"host": "two"}]}"#,
+            "config.json",
+        );
+
+        assert_eq!(lookup(&json, "hosts"), Ok(Some("a,\"b,c\",3".to_string())));
+        assert_eq!(
+            lookup(&json, "upstreams[1].host"),
+            Ok(Some("two".to_string()))
+        );
+        assert_eq!(lookup(&json, "upstreams[2].host"), Ok(None));
+    }
+
+    #[test]
+    fn test_type_mismatches() {
+        let json = Json::parse(
+            r#"{"database": 5, "server": {"ports": [1, {"port": 2}]}}"#,
+            "config.json",
+        );
+
+        assert_eq!(
+            lookup(&json, "database.host"),
+            Err("expected an object at 'database' in config.json, found number".to_string())
+        );
+        assert_eq!(
+            lookup(&json, "server.ports"),
+            Err("expected a value at 'server.ports[1]' in config.json, found object".to_string())
+        );
+        assert_eq!(
+            lookup(&json, "server.ports.port"),
+            Err("expected an object at 'server.ports' in config.json, found array".to_string())
+        );
+    }
+
+    #[test]
+    fn test_syntax_errors() {
+        let json = Json::parse("{\n  \"port\": 80,\n  oops\n}", "config.json");
+
+        assert!(matches!(
+            &json.errors[..],
+            [ConfigFieldError::SourceError { line: Some(3), message, .. }] if message == "key must be a string"
+        ));
+
+        let json = Json::parse("[1, 2]", "config.json");
+
+        assert!(matches!(
+            &json.errors[..],
+            [ConfigFieldError::SourceError { line: None, message, .. }] if message == "expected an object at the root, found array"
+        ));
+    }
+}
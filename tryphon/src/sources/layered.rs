@@ -0,0 +1,145 @@
+use crate::config_field_error::ConfigFieldError;
+use crate::field_path::FieldPath;
+use crate::source::{Source, SourceValue};
+
+/// A stack of [`Source`]s with explicit precedence.
+///
+/// Layers are added from the lowest to the highest precedence with [`layer`](Layered::layer).
+/// Every field is resolved against the topmost layer first: all of its `#[env]` names and its
+/// path are tried there, and only if the layer has no value for the field is the next layer
+/// down consulted. Values from `#[default]` attributes are used when no layer has a value, so
+/// they always have the lowest precedence.
+///
+/// Errors reported by the layers themselves, like syntax errors in files, are collected from
+/// every layer, so a broken file is reported together with all field errors regardless of
+/// whether a higher layer provided every value.
+///
+/// # Examples
+///
+/// A stack of `#[default]` values < shared `.env` file < local `.env` file < process environment.
+/// File formats can be mixed freely, e.g. with a [`Toml`](crate::Toml) file at the bottom:
+///
+/// ```rust
+/// use tryphon::{Config, DotEnv, Env, EnvOverrides, Layered};
+///
+/// #[derive(Config)]
+/// struct AppConfig {
+///     #[env("HOST")]
+///     #[default("localhost")]
+///     host: String,
+///
+///     #[env("PORT")]
+///     port: u16,
+///
+///     #[env("WORKERS")]
+///     workers: u8,
+/// }
+///
+/// let mut overrides = EnvOverrides::init();
+/// overrides.set("WORKERS", "8");
+///
+/// let source = Layered::new()
+///     .layer(DotEnv::parse("PORT=80\nWORKERS=2", ".env"))
+///     .layer(DotEnv::parse("PORT=8080", ".env.local"))
+///     .layer(Env);
+///
+/// let config = AppConfig::load_from(&source).unwrap();
+///
+/// assert_eq!(config.host, "localhost");
+/// assert_eq!(config.port, 8080);
+/// assert_eq!(config.workers, 8);
+/// ```
+#[derive(Default)]
+pub struct Layered<'a> {
+    layers: Vec<Box<dyn Source + 'a>>,
+}
+
+impl<'a> Layered<'a> {
+    /// Creates an empty stack.
+    pub fn new() -> Self {
+        Layered { layers: Vec::new() }
+    }
+
+    /// Adds `source` on top of the stack, taking precedence over all layers added before it.
+    pub fn layer(mut self, source: impl Source + 'a) -> Self {
+        self.layers.push(Box::new(source));
+        self
+    }
+}
+
+impl Source for Layered<'_> {
+    fn lookup(&self, key: &str) -> Result<Option<SourceValue>, String> {
+        for layer in self.layers.iter().rev() {
+            if let Some(value) = layer.lookup(key)? {
+                return Ok(Some(value));
+            }
+        }
+        Ok(None)
+    }
+
+    fn lookup_path(&self, path: &FieldPath) -> Result<Option<SourceValue>, String> {
+        for layer in self.layers.iter().rev() {
+            if let Some(value) = layer.lookup_path(path)? {
+                return Ok(Some(value));
+            }
+        }
+        Ok(None)
+    }
+
+    fn lookup_credential(&self, name: &str) -> Result<Option<SourceValue>, String> {
+        for layer in self.layers.iter().rev() {
+            if let Some(value) = layer.lookup_credential(name)? {
+                return Ok(Some(value));
+            }
+        }
+        Ok(None)
+    }
+
+    fn resolve(
+        &self,
+        env_vars: &[&str],
+        path: &FieldPath,
+    ) -> Result<Option<(SourceValue, String)>, String> {
+        for layer in self.layers.iter().rev() {
+            if let Some(resolved) = layer.resolve(env_vars, path)? {
+                return Ok(Some(resolved));
+            }
+        }
+        Ok(None)
+    }
+
+    fn keys(&self) -> Result<Vec<String>, String> {
+        let mut keys = Vec::new();
+
+        for layer in &self.layers {
+            for key in layer.keys()? {
+                if !keys.contains(&key) {
+                    keys.push(key);
+                }
+            }
+        }
+
+        Ok(keys)
+    }
+
+    fn keys_at(&self, path: &FieldPath) -> Result<Vec<String>, String> {
+        let mut keys = Vec::new();
+
+        for layer in &self.layers {
+            for key in layer.keys_at(path)? {
+                if !keys.contains(&key) {
+                    keys.push(key);
+                }
+            }
+        }
+
+        Ok(keys)
+    }
+
+    fn errors(&self) -> Vec<ConfigFieldError> {
+        self.layers
+            .iter()
+            .flat_map(|layer| layer.errors())
+            .collect()
+    }
+}
@@ -0,0 +1,41 @@
+//! Built-in implementations of [`Source`](crate::Source).
+//!
+//! - [`Env`] - the process environment, respecting thread-local [`EnvOverrides`](crate::EnvOverrides)
+//! - [`DotEnv`] - variables from one or more `.env` files
+//! - [`Directory`] - one file per key, like mounted Kubernetes ConfigMaps and Secrets
+//! - [`Credentials`] - systemd credentials, for fields with `#[credential]` attributes
+//! - [`Args`] - command-line arguments, with flags derived from the paths of the fields
+//! - [`Toml`] - values from a TOML document (requires the `toml` feature)
+//! - [`Json`] - values from a JSON document (requires the `json` feature)
+//! - [`Yaml`] - values from a YAML document (requires the `yaml` feature)
+//!
+//! Sources are combined with [`Layered`], which resolves every field against a stack of
+//! sources with explicit precedence.
+
+mod args;
+mod credentials;
+mod directory;
+#[cfg(any(feature = "json", feature = "toml", feature = "yaml"))]
+mod document;
+mod dotenv;
+mod env;
+#[cfg(feature = "json")]
+mod json_file;
+mod layered;
+#[cfg(feature = "toml")]
+mod toml_file;
+#[cfg(feature = "yaml")]
+mod yaml_file;
+
+pub use args::*;
+pub use credentials::*;
+pub use directory::*;
+pub use dotenv::*;
+pub use env::*;
+#[cfg(feature = "json")]
+pub use json_file::*;
+pub use layered::*;
+#[cfg(feature = "toml")]
+pub use toml_file::*;
+#[cfg(feature = "yaml")]
+pub use yaml_file::*;
@@ -0,0 +1,259 @@
+use crate::config_field_error::ConfigFieldError;
+use crate::field_path::FieldPath;
+use crate::source::{Source, SourceValue};
+use crate::sources::document::{self, DocumentValue, Node};
+use std::path::Path;
+use toml::{Table, Value};
+
+/// A [`Source`] reading values from a TOML document.
+///
+/// Fields are looked up by their [`FieldPath`]: nested `#[config]` structs map to TOML tables
+/// named after the field, and `#[env]` fields map to keys named after the field. Strings,
+/// integers, floats, booleans and datetimes are passed to
+/// [`ConfigValueDecoder`](crate::ConfigValueDecoder) in their textual form. Arrays of values
+/// are joined with `,`, quoting elements where needed, so they decode into collections like
+/// `Vec<String>`, and arrays of tables are read by `#[config(indexed)]` lists.
+///
+/// A value of the wrong TOML type (e.g. a table where a plain value is expected) is reported
+/// as an error for the field, naming the table path where the mismatch was found. Syntax errors
+/// are reported as [`ConfigFieldError::SourceError`] with the line number.
+///
+/// Values are labelled with the origin they were created with, usually the path of the file.
+///
+/// Requires the `toml` feature (enabled by default).
+///
+/// # Examples
+///
+/// ```rust
+/// use tryphon::{Config, Toml};
+///
+/// #[derive(Config)]
+/// struct DatabaseConfig {
+///     #[env("DB_HOST")]
+///     host: String,
+///
+///     #[env("DB_PORT")]
+///     port: u16,
+/// }
+///
+/// #[derive(Config)]
+/// struct AppConfig {
+///     #[env("APP_NAME")]
+///     name: String,
+///
+///     #[config]
+///     database: DatabaseConfig,
+/// }
+///
+/// let toml = Toml::parse(
+///     r#"
+/// name = "tryphon"
+///
+/// [database]
+/// host = "localhost"
+/// port = 5432
+/// "#,
+///     "config.toml",
+/// );
+///
+/// let config = AppConfig::load_from(&toml).unwrap();
+///
+/// assert_eq!(config.name, "tryphon");
+/// assert_eq!(config.database.port, 5432);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Toml {
+    table: Table,
+    origin: String,
+    errors: Vec<ConfigFieldError>,
+}
+
+impl Toml {
+    /// Reads and parses a TOML file.
+    ///
+    /// If the file can't be read or parsed, the error is recorded and reported when a config
+    /// is loaded from this source.
+    pub fn from_path(path: impl AsRef<Path>) -> Toml {
+        let path = path.as_ref();
+        let origin = path.display().to_string();
+
+        match std::fs::read_to_string(path) {
+            Ok(contents) => Self::parse(&contents, &origin),
+            Err(e) => Toml {
+                origin: origin.clone(),
+                errors: vec![ConfigFieldError::SourceError {
+                    origin,
+                    line: None,
+                    message: e.to_string(),
+                }],
+                ..Toml::default()
+            },
+        }
+    }
+
+    /// Parses a TOML document. The `origin` is used to label values and errors,
+    /// usually it's the name of the file the contents come from.
+    pub fn parse(contents: &str, origin: &str) -> Toml {
+        match contents.parse::<Table>() {
+            Ok(table) => Toml {
+                table,
+                origin: origin.to_string(),
+                errors: Vec::new(),
+            },
+            Err(e) => Toml {
+                origin: origin.to_string(),
+                errors: vec![ConfigFieldError::SourceError {
+                    origin: origin.to_string(),
+                    line: e
+                        .span()
+                        .map(|span| contents[..span.start].matches('\n').count() + 1),
+                    message: e.message().to_string(),
+                }],
+                ..Toml::default()
+            },
+        }
+    }
+}
+
+impl DocumentValue for Value {
+    type Nested = Table;
+
+    const NESTED_NAME: &'static str = "a table";
+
+    fn node(&self) -> Node<'_, Value> {
+        match self {
+            Value::String(s) => Node::Scalar(s.clone()),
+            Value::Integer(i) => Node::Scalar(i.to_string()),
+            Value::Float(f) => Node::Scalar(f.to_string()),
+            Value::Boolean(b) => Node::Scalar(b.to_string()),
+            Value::Datetime(d) => Node::Scalar(d.to_string()),
+            Value::Array(elements) => Node::Array(elements),
+            Value::Table(table) => Node::Nested(table),
+        }
+    }
+
+    fn get<'a>(table: &'a Table, key: &str) -> Option<&'a Value> {
+        table.get(key)
+    }
+
+    fn keys(table: &Table) -> Vec<String> {
+        table.keys().cloned().collect()
+    }
+
+    fn type_name(&self) -> &'static str {
+        self.type_str()
+    }
+}
+
+impl Source for Toml {
+    fn lookup(&self, _key: &str) -> Result<Option<SourceValue>, String> {
+        Ok(None)
+    }
+
+    fn lookup_path(&self, path: &FieldPath) -> Result<Option<SourceValue>, String> {
+        document::lookup_path::<Value>(&self.table, path, &self.origin)
+    }
+
+    fn keys_at(&self, path: &FieldPath) -> Result<Vec<String>, String> {
+        document::keys_at::<Value>(&self.table, path, &self.origin)
+    }
+
+    fn errors(&self) -> Vec<ConfigFieldError> {
+        self.errors.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lookup(toml: &Toml, path: &str) -> Result<Option<String>, String> {
+        toml.lookup_path(&path.parse().unwrap())
+            .map(|v| v.map(|v| v.value))
+    }
+
+    #[test]
+    fn test_scalar_values() {
+        let toml = Toml::parse(
+            "name = \"app\"\nport = 8080\nratio = 0.5\ndebug = true\nstarted = 1979-05-27T07:32:00Z\n",
+            "config.toml",
+        );
+
+        assert_eq!(lookup(&toml, "name"), Ok(Some("app".to_string())));
+        assert_eq!(lookup(&toml, "port"), Ok(Some("8080".to_string())));
+        assert_eq!(lookup(&toml, "ratio"), Ok(Some("0.5".to_string())));
+        assert_eq!(lookup(&toml, "debug"), Ok(Some("true".to_string())));
+        assert_eq!(
+            lookup(&toml, "started"),
+            Ok(Some("1979-05-27T07:32:00Z".to_string()))
+        );
+        assert_eq!(lookup(&toml, "missing"), Ok(None));
+    }
+
+    #[test]
+    fn test_nested_tables() {
+        let toml = Toml::parse(
+            "[database]\nhost = \"db\"\n[database.pool]\nsize = 4\n",
+            "config.toml",
+        );
+
+        assert_eq!(lookup(&toml, "database.host"), Ok(Some("db".to_string())));
+        assert_eq!(
+            lookup(&toml, "database.pool.size"),
+            Ok(Some("4".to_string()))
+        );
+        assert_eq!(lookup(&toml, "database.pool.missing"), Ok(None));
+        assert_eq!(lookup(&toml, "cache.host"), Ok(None));
+    }
+
+    #[test]
+    fn test_arrays() {
+        let toml = Toml::parse(
+            "hosts = [\"a\", \"b,c\"]\nempty = []\n[[upstreams]]\nhost = \"one\"\n[[upstreams]]\nhost = \"two\"\n",
+            "config.toml",
+        );
+
+        assert_eq!(lookup(&toml, "hosts"), Ok(Some("a,\"b,c\"".to_string())));
+        assert_eq!(lookup(&toml, "empty"), Ok(Some(String::new())));
+        assert_eq!(
+            lookup(&toml, "upstreams[1].host"),
+            Ok(Some("two".to_string()))
+        );
+        assert_eq!(lookup(&toml, "upstreams[2].host"), Ok(None));
+    }
+
+    #[test]
+    fn test_type_mismatches() {
+        let toml = Toml::parse(
+            "database = 5\n[server]\nports = [1, { port = 2 }]\n",
+            "config.toml",
+        );
+
+        assert_eq!(
+            lookup(&toml, "database.host"),
+            Err("expected a table at 'database' in config.toml, found integer".to_string())
+        );
+        assert_eq!(
+            lookup(&toml, "server.ports"),
+            Err("expected a value at 'server.ports[1]' in config.toml, found table".to_string())
+        );
+        assert_eq!(
+            lookup(&toml, "server.ports.port"),
+            Err("expected a table at 'server.ports' in config.toml, found array".to_string())
+        );
+        assert_eq!(
+            lookup(&toml, "server"),
+            Err("expected a value at 'server' in config.toml, found table".to_string())
+        );
+    }
+
+    #[test]
+    fn test_syntax_error_has_line_number() {
+        let toml = Toml::parse("name = \"app\"\nport = = 1\n", "config.toml");
+
+        assert!(matches!(
+            &toml.errors[..],
+            [ConfigFieldError::SourceError { origin, line: Some(2), .. }] if origin == "config.toml"
+        ));
+    }
+}
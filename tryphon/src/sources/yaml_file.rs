@@ -0,0 +1,265 @@
+use crate::config_field_error::ConfigFieldError;
+use crate::field_path::FieldPath;
+use crate::source::{Source, SourceValue};
+use crate::sources::document::{self, DocumentValue, Node};
+use std::path::Path;
+use yaml_rust2::yaml::Hash;
+use yaml_rust2::{Yaml as YamlValue, YamlLoader};
+
+/// A [`Source`] reading values from a YAML document.
+///
+/// Works like [`Toml`](crate::Toml): fields are looked up by their [`FieldPath`], nested
+/// `#[config]` structs map to mappings named after the field, and `#[env]` fields map to keys
+/// named after the field. Strings, numbers and booleans are passed to
+/// [`ConfigValueDecoder`](crate::ConfigValueDecoder) in their textual form, so custom decoders
+/// work the same as for environment variables. `null` (or `~`) is treated as a missing value.
+///
+/// Only the first document of a multi-document stream is used.
+///
+/// Values are labelled with the origin they were created with, usually the path of the file.
+///
+/// Requires the `yaml` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use tryphon::{Config, Yaml};
+///
+/// #[derive(Config)]
+/// struct DatabaseConfig {
+///     #[env("DB_HOST")]
+///     host: String,
+/// }
+///
+/// #[derive(Config)]
+/// struct AppConfig {
+///     #[env("PORT")]
+///     port: u16,
+///
+///     #[config]
+///     database: DatabaseConfig,
+/// }
+///
+/// let yaml = Yaml::parse("port: 8080\ndatabase:\n  host: db.internal\n", "config.yaml");
+/// let config = AppConfig::load_from(&yaml).unwrap();
+///
+/// assert_eq!(config.port, 8080);
+/// assert_eq!(config.database.host, "db.internal");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Yaml {
+    mapping: Hash,
+    origin: String,
+    errors: Vec<ConfigFieldError>,
+}
+
+impl Yaml {
+    /// Reads and parses a YAML file.
+    ///
+    /// If the file can't be read or parsed, the error is recorded and reported when a config
+    /// is loaded from this source.
+    pub fn from_path(path: impl AsRef<Path>) -> Yaml {
+        let path = path.as_ref();
+        let origin = path.display().to_string();
+
+        match std::fs::read_to_string(path) {
+            Ok(contents) => Self::parse(&contents, &origin),
+            Err(e) => Yaml::failed(&origin, None, e.to_string()),
+        }
+    }
+
+    /// Parses a YAML document, which must be a mapping (or empty). The `origin` is used to
+    /// label values and errors, usually it's the name of the file the contents come from.
+    pub fn parse(contents: &str, origin: &str) -> Yaml {
+        match YamlLoader::load_from_str(contents) {
+            Ok(documents) => match documents.into_iter().next() {
+                None | Some(YamlValue::Null) => Yaml {
+                    origin: origin.to_string(),
+                    ..Yaml::default()
+                },
+                Some(YamlValue::Hash(mapping)) => Yaml {
+                    mapping,
+                    origin: origin.to_string(),
+                    errors: Vec::new(),
+                },
+                Some(other) => Yaml::failed(
+                    origin,
+                    None,
+                    format!(
+                        "expected a mapping at the root, found {}",
+                        type_name(&other)
+                    ),
+                ),
+            },
+            Err(e) => Yaml::failed(origin, Some(e.marker().line()), e.info().to_string()),
+        }
+    }
+
+    fn failed(origin: &str, line: Option<usize>, message: String) -> Yaml {
+        Yaml {
+            origin: origin.to_string(),
+            errors: vec![ConfigFieldError::SourceError {
+                origin: origin.to_string(),
+                line,
+                message,
+            }],
+            ..Yaml::default()
+        }
+    }
+}
+
+fn type_name(value: &YamlValue) -> &'static str {
+    match value {
+        YamlValue::Real(_) | YamlValue::Integer(_) => "number",
+        YamlValue::String(_) => "string",
+        YamlValue::Boolean(_) => "boolean",
+        YamlValue::Array(_) => "sequence",
+        YamlValue::Hash(_) => "mapping",
+        YamlValue::Alias(_) => "alias",
+        YamlValue::Null => "null",
+        YamlValue::BadValue => "invalid value",
+    }
+}
+
+impl DocumentValue for YamlValue {
+    type Nested = Hash;
+
+    const NESTED_NAME: &'static str = "a mapping";
+
+    fn node(&self) -> Node<'_, YamlValue> {
+        match self {
+            YamlValue::Null => Node::Null,
+            YamlValue::String(s) | YamlValue::Real(s) => Node::Scalar(s.clone()),
+            YamlValue::Integer(i) => Node::Scalar(i.to_string()),
+            YamlValue::Boolean(b) => Node::Scalar(b.to_string()),
+            YamlValue::Array(elements) => Node::Array(elements),
+            YamlValue::Hash(mapping) => Node::Nested(mapping),
+            YamlValue::Alias(_) | YamlValue::BadValue => Node::Other,
+        }
+    }
+
+    fn get<'a>(mapping: &'a Hash, key: &str) -> Option<&'a YamlValue> {
+        mapping.get(&YamlValue::String(key.to_string()))
+    }
+
+    /// Returns the string keys of a mapping; other keys can't be looked up.
+    fn keys(mapping: &Hash) -> Vec<String> {
+        mapping
+            .keys()
+            .filter_map(|key| key.as_str().map(str::to_string))
+            .collect()
+    }
+
+    fn type_name(&self) -> &'static str {
+        type_name(self)
+    }
+}
+
+impl Source for Yaml {
+    fn lookup(&self, _key: &str) -> Result<Option<SourceValue>, String> {
+        Ok(None)
+    }
+
+    fn lookup_path(&self, path: &FieldPath) -> Result<Option<SourceValue>, String> {
+        document::lookup_path::<YamlValue>(&self.mapping, path, &self.origin)
+    }
+
+    fn keys_at(&self, path: &FieldPath) -> Result<Vec<String>, String> {
+        document::keys_at::<YamlValue>(&self.mapping, path, &self.origin)
+    }
+
+    fn errors(&self) -> Vec<ConfigFieldError> {
+        self.errors.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lookup(yaml: &Yaml, path: &str) -> Result<Option<String>, String> {
+        yaml.lookup_path(&path.parse().unwrap())
+            .map(|v| v.map(|v| v.value))
+    }
+
+    #[test]
+    fn test_values() {
+        let yaml = Yaml::parse(
+            "name: app\nport: 8080\nratio: 0.5\ndebug: true\nunset: ~\ndatabase:\n  host: db\n  pool:\n    size: 4\n",
+            "config.yaml",
+        );
+
+        assert_eq!(lookup(&yaml, "name"), Ok(Some("app".to_string())));
+        assert_eq!(lookup(&yaml, "port"), Ok(Some("8080".to_string())));
+        assert_eq!(lookup(&yaml, "ratio"), Ok(Some("0.5".to_string())));
+        assert_eq!(lookup(&yaml, "debug"), Ok(Some("true".to_string())));
+        assert_eq!(lookup(&yaml, "unset"), Ok(None));
+        assert_eq!(lookup(&yaml, "database.host"), Ok(Some("db".to_string())));
+        assert_eq!(
+            lookup(&yaml, "database.pool.size"),
+            Ok(Some("4".to_string()))
+        );
+        assert_eq!(lookup(&yaml, "cache.host"), Ok(None));
+    }
+
+    #[test]
+    fn test_empty_document() {
+        let yaml = Yaml::parse("", "config.yaml");
+
+        assert!(yaml.errors.is_empty());
+        assert_eq!(lookup(&yaml, "name"), Ok(None));
+    }
+
+    #[test]
+    fn test_arrays() {
+        let yaml = Yaml::parse(
+            "hosts: [a, \"b,c\"]\nupstreams:\n  - host: one\n  - host: two\n",
+            "config.yaml",
+        );
+
+        assert_eq!(lookup(&yaml, "hosts"), Ok(Some("a,\"b,c\"".to_string())));
+        assert_eq!(
+            lookup(&yaml, "upstreams[1].host"),
+            Ok(Some("two".to_string()))
+        );
+        assert_eq!(lookup(&yaml, "upstreams[2].host"), Ok(None));
+    }
+
+    #[test]
+    fn test_type_mismatches() {
+        let yaml = Yaml::parse(
+            "database: 5\nserver:\n  ports: [1, {port: 2}]\n",
+            "config.yaml",
+        );
+
+        assert_eq!(
+            lookup(&yaml, "database.host"),
+            Err("expected a mapping at 'database' in config.yaml, found number".to_string())
+        );
+        assert_eq!(
+            lookup(&yaml, "server.ports"),
+            Err("expected a value at 'server.ports[1]' in config.yaml, found mapping".to_string())
+        );
+        assert_eq!(
+            lookup(&yaml, "server.ports.port"),
+            Err("expected a mapping at 'server.ports' in config.yaml, found sequence".to_string())
+        );
+    }
+
+    #[test]
+    fn test_syntax_errors() {
+        let yaml = Yaml::parse("port: 80\nname: [unclosed\n", "config.yaml");
+
+        assert!(matches!(
+            &yaml.errors[..],
+            [ConfigFieldError::SourceError { line: Some(_), .. }]
+        ));
+
+        let yaml = Yaml::parse("- 1\n- 2\n", "config.yaml");
+
+        assert!(matches!(
+            &yaml.errors[..],
+            [ConfigFieldError::SourceError { line: None, message, .. }] if message == "expected a mapping at the root, found sequence"
+        ));
+    }
+}
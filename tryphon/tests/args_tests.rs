@@ -0,0 +1,188 @@
+use tryphon::{Args, Config, ConfigFieldError, Env, EnvOverrides, FieldProvenance, Layered};
+
+#[derive(Debug, Config)]
+struct DatabaseConfig {
+    #[env("DB_HOST")]
+    #[default("localhost")]
+    host: String,
+
+    #[env("DB_MAX_CONNECTIONS")]
+    #[default(10)]
+    max_connections: u32,
+}
+
+#[derive(Debug, Config)]
+struct AppConfig {
+    #[env("APP_PORT")]
+    #[env("PORT")]
+    port: u16,
+
+    #[env("OFFSET")]
+    offset: Option<i32>,
+
+    #[config]
+    database: DatabaseConfig,
+}
+
+fn load(args: &[&str]) -> Result<AppConfig, tryphon::ConfigError> {
+    let args = Args::parse::<AppConfig>(args.iter().copied());
+    AppConfig::load_from(&Layered::new().layer(Env).layer(args))
+}
+
+#[test]
+fn test_flags_are_derived_from_field_paths() {
+    let _overrides = EnvOverrides::init();
+
+    let config = load(&[
+        "--port",
+        "9090",
+        "--database-host=db2",
+        "--database-max-connections",
+        "50",
+        "--offset",
+        "-5",
+    ])
+    .expect("Failed to load config");
+
+    assert_eq!(config.port, 9090);
+    assert_eq!(config.offset, Some(-5));
+    assert_eq!(config.database.host, "db2");
+    assert_eq!(config.database.max_connections, 50);
+}
+
+#[test]
+fn test_set_by_dotted_path() {
+    let _overrides = EnvOverrides::init();
+
+    let config = load(&[
+        "--port=1",
+        "--set",
+        "database.host=db2",
+        "--set=database.max_connections=3",
+    ])
+    .expect("Failed to load config");
+
+    assert_eq!(config.database.host, "db2");
+    assert_eq!(config.database.max_connections, 3);
+}
+
+#[test]
+fn test_args_take_precedence_over_environment() {
+    let mut overrides = EnvOverrides::init();
+    overrides.set("APP_PORT", "8080").set("DB_HOST", "db1");
+
+    let args = Args::parse::<AppConfig>(["--port", "9090"]);
+    let (config, report) = AppConfig::load_from_with_report(&Layered::new().layer(Env).layer(args))
+        .expect("Failed to load config");
+
+    assert_eq!(config.port, 9090);
+    assert_eq!(config.database.host, "db1");
+
+    assert_eq!(
+        report.get("port").unwrap().provenance,
+        FieldProvenance::Found {
+            key: "port".to_string(),
+            origin: "command line".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_last_value_wins() {
+    let _overrides = EnvOverrides::init();
+
+    let config = load(&["--port", "1", "--set", "port=2"]).expect("Failed to load config");
+
+    assert_eq!(config.port, 2);
+}
+
+#[test]
+fn test_invalid_arguments_are_reported() {
+    let _overrides = EnvOverrides::init();
+
+    let error = load(&[
+        "--port",
+        "9090",
+        "positional",
+        "--unknown",
+        "1",
+        "--set",
+        "database.user=admin",
+        "--set",
+        "database.host",
+        "--database-host",
+    ])
+    .expect_err("Expected argument errors");
+
+    let messages = error
+        .field_errors
+        .iter()
+        .map(|error| match error {
+            ConfigFieldError::SourceError {
+                origin, message, ..
+            } if origin == "command line" => message.as_str(),
+            other => panic!("Unexpected error: {:?}", other),
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        messages,
+        vec![
+            "unexpected argument 'positional'",
+            "unknown argument '--unknown'",
+            "unexpected argument '1'",
+            "unknown field 'database.user' in '--set'",
+            "expected PATH=VALUE after '--set', got 'database.host'",
+            "missing value for '--database-host'",
+        ]
+    );
+}
+
+#[derive(Debug, Config)]
+struct ClashingConfig {
+    #[env("DATABASE_HOST")]
+    database_host: String,
+
+    #[config]
+    database: DatabaseConfig,
+}
+
+#[test]
+fn test_flags_shared_by_fields_are_reported() {
+    let _overrides = EnvOverrides::init();
+
+    let args = Args::parse::<ClashingConfig>(["--database-host", "db1"]);
+    let error = ClashingConfig::load_from(&args).expect_err("Expected an ambiguous flag");
+
+    assert!(error.field_errors.iter().any(|error| matches!(
+        error,
+        ConfigFieldError::SourceError { message, .. }
+          if message == "ambiguous argument '--database-host' matches fields 'database_host', \
+                         'database.host', use '--set PATH=VALUE' instead"
+    )));
+
+    let args =
+        Args::parse::<ClashingConfig>(["--set", "database_host=db1", "--set", "database.host=db2"]);
+    let config = ClashingConfig::load_from(&args).expect("Failed to load config");
+
+    assert_eq!(config.database_host, "db1");
+    assert_eq!(config.database.host, "db2");
+}
+
+#[test]
+fn test_help() {
+    let args = Args::parse::<AppConfig>(["-h"]);
+    assert!(args.help_requested());
+
+    assert_eq!(
+        Args::help::<AppConfig>(),
+        "Options:
+  --port <VALUE>                      env: APP_PORT, PORT
+  --offset <VALUE>                    env: OFFSET
+  --database-host <VALUE>             env: DB_HOST; default: localhost
+  --database-max-connections <VALUE>  env: DB_MAX_CONNECTIONS; default: 10
+  --set <PATH=VALUE>                  set any field by its path, e.g. database.port=5432
+  -h, --help                          print this help
+"
+    );
+}
@@ -0,0 +1,81 @@
+use tryphon::{ByteSize, Config, ConfigFieldError, EnvOverrides};
+
+#[derive(Debug, Config)]
+struct ServerConfig {
+    #[env("CACHE_SIZE")]
+    #[default("64MiB")]
+    cache_size: ByteSize,
+
+    #[env("MAX_BODY_SIZE", byte_size)]
+    #[default("1MB")]
+    max_body_size: usize,
+
+    #[env("BUFFER_SIZE", byte_size)]
+    buffer_size: Option<u64>,
+
+    #[env("CHUNK_SIZE", byte_size)]
+    #[default("16k")]
+    chunk_size: u32,
+
+    #[env("TIER_SIZES", byte_size)]
+    tier_sizes: Option<Vec<u64>>,
+}
+
+#[test]
+fn test_defaults_are_decoded() {
+    let _overrides = EnvOverrides::init();
+
+    let config = ServerConfig::load().expect("Failed to load config");
+
+    assert_eq!(config.cache_size, ByteSize(64 * 1024 * 1024));
+    assert_eq!(config.max_body_size, 1_000_000);
+    assert_eq!(config.buffer_size, None);
+    assert_eq!(config.chunk_size, 16 * 1024);
+    assert_eq!(config.tier_sizes, None);
+}
+
+#[test]
+fn test_sizes_are_read_from_env() {
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("CACHE_SIZE", "2 GB")
+        .set("MAX_BODY_SIZE", "512kib")
+        .set("BUFFER_SIZE", "4k")
+        .set("CHUNK_SIZE", "1MiB")
+        .set("TIER_SIZES", "64MiB, 1GB");
+
+    let config = ServerConfig::load().expect("Failed to load config");
+
+    assert_eq!(config.cache_size.bytes(), 2_000_000_000);
+    assert_eq!(config.max_body_size, 512 * 1024);
+    assert_eq!(config.buffer_size, Some(4096));
+    assert_eq!(config.chunk_size, 1024 * 1024);
+    assert_eq!(
+        config.tier_sizes,
+        Some(vec![64 * 1024 * 1024, 1_000_000_000])
+    );
+}
+
+#[test]
+fn test_overflow_is_reported() {
+    let mut overrides = EnvOverrides::init();
+    overrides.set("BUFFER_SIZE", "20000PB");
+
+    let error = ServerConfig::load().expect_err("Expected overflowing size");
+
+    match &error.field_errors[..] {
+        [
+            ConfigFieldError::ParsingError {
+                field_name,
+                raw,
+                error,
+                ..
+            },
+        ] => {
+            assert_eq!(field_name.as_deref(), Some("buffer_size"));
+            assert_eq!(raw, "20000PB");
+            assert_eq!(error.message(), "byte size '20000PB' is too large");
+        }
+        other => panic!("Unexpected errors: {:?}", other),
+    }
+}
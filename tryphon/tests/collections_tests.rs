@@ -0,0 +1,88 @@
+use std::collections::{BTreeSet, HashSet};
+use tryphon::{Config, ConfigFieldError, EnvOverrides, Secret};
+
+#[derive(Debug, Config)]
+struct AppConfig {
+    #[env("ALLOWED_ORIGINS")]
+    #[default(Vec::new())]
+    allowed_origins: Vec<String>,
+
+    #[env("BROKERS", separator = ";")]
+    brokers: Vec<String>,
+
+    #[env("PORTS")]
+    ports: HashSet<u16>,
+
+    #[env("PRIORITIES", separator = "|")]
+    priorities: Option<BTreeSet<i32>>,
+
+    #[env("TOKENS", separator = " ")]
+    tokens: Option<Secret<Vec<String>>>,
+}
+
+#[test]
+fn test_collections() {
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("ALLOWED_ORIGINS", "https://a.example, https://b.example")
+        .set("BROKERS", "kafka-1:9092;kafka-2:9092")
+        .set("PORTS", "80,443,80")
+        .set("PRIORITIES", "3|1|2")
+        .set("TOKENS", "abc def");
+
+    let config = AppConfig::load().expect("Failed to load config");
+
+    assert_eq!(
+        config.allowed_origins,
+        vec!["https://a.example", "https://b.example"]
+    );
+    assert_eq!(config.brokers, vec!["kafka-1:9092", "kafka-2:9092"]);
+    assert_eq!(config.ports, HashSet::from([80, 443]));
+    assert_eq!(config.priorities, Some(BTreeSet::from([1, 2, 3])));
+    assert_eq!(**config.tokens.unwrap(), vec!["abc", "def"]);
+}
+
+#[test]
+fn test_quoted_elements() {
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("ALLOWED_ORIGINS", r#""a,b", c\,d"#)
+        .set("BROKERS", "")
+        .set("PORTS", "80");
+
+    let config = AppConfig::load().expect("Failed to load config");
+
+    assert_eq!(config.allowed_origins, vec!["a,b", "c,d"]);
+    assert!(config.brokers.is_empty());
+}
+
+#[test]
+fn test_failing_element_is_reported() {
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("BROKERS", "kafka-1:9092")
+        .set("PORTS", "80, http, 443");
+
+    let error = AppConfig::load().expect_err("Expected a parsing error");
+
+    match &error.field_errors[..] {
+        [
+            ConfigFieldError::ParsingError {
+                field_name,
+                raw,
+                error,
+                env_var_name,
+                ..
+            },
+        ] => {
+            assert_eq!(field_name.as_deref(), Some("ports"));
+            assert_eq!(raw, "80, http, 443");
+            assert_eq!(
+                error.message(),
+                "invalid element 'http' at index 1: invalid digit found in string"
+            );
+            assert_eq!(env_var_name, "PORTS");
+        }
+        other => panic!("Unexpected errors: {:?}", other),
+    }
+}
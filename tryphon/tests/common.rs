@@ -1,4 +1,10 @@
+#![allow(dead_code)]
+
+use std::fs;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
 use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 pub static TEST_MUTEX: Mutex<()> = Mutex::new(());
 
@@ -10,3 +16,80 @@ macro_rules! clear_test_env_vars {
       )*
   };
 }
+
+static TEMP_DIR_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// A directory under the system temporary directory, removed with its files when dropped.
+pub struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    /// Creates an empty directory, unique to the test process and this call.
+    pub fn new(name: &str) -> TempDir {
+        let path = std::env::temp_dir().join(format!(
+            "tryphon-{}-{}-{}",
+            name,
+            std::process::id(),
+            TEMP_DIR_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+        TempDir { path }
+    }
+
+    /// Writes a file named `name` in the directory, returning its path.
+    pub fn write(&self, name: &str, contents: &str) -> PathBuf {
+        let path = self.path.join(name);
+        fs::write(&path, contents).unwrap();
+        path
+    }
+}
+
+impl Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl AsRef<Path> for TempDir {
+    fn as_ref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+/// A file in its own [`TempDir`], removed when dropped.
+pub struct TempFile {
+    path: PathBuf,
+    _dir: TempDir,
+}
+
+impl Deref for TempFile {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl AsRef<Path> for TempFile {
+    fn as_ref(&self) -> &Path {
+        &self.path
+    }
+}
+
+/// Writes `contents` to a temporary file named `name`, removed when the returned guard is
+/// dropped.
+pub fn write_temp_file(name: &str, contents: &str) -> TempFile {
+    let dir = TempDir::new("file");
+    let path = dir.write(name, contents);
+    TempFile { path, _dir: dir }
+}
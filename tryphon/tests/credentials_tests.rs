@@ -0,0 +1,196 @@
+mod common;
+
+use common::TempDir;
+use std::fs;
+use tryphon::{
+    Config, ConfigFieldError, Credentials, Env, EnvOverrides, FieldProvenance, Layered, Secret,
+};
+
+#[derive(Debug, Config)]
+struct DbConfig {
+    #[env("DB_USER")]
+    user: String,
+
+    #[credential("db-password")]
+    #[env("DB_PASSWORD")]
+    password: Secret<String>,
+
+    #[credential("db-port")]
+    #[env("DB_PORT")]
+    #[default(5432)]
+    port: u16,
+}
+
+#[test]
+fn test_credentials_are_read_from_credentials_directory() {
+    let dir = TempDir::new("basic");
+    fs::write(dir.join("db-password"), "hunter2\n").unwrap();
+
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("CREDENTIALS_DIRECTORY", dir.to_str().unwrap())
+        .set("DB_USER", "admin")
+        .set("DB_PASSWORD", "from-env");
+
+    let (config, report) = DbConfig::load_with_report().expect("Failed to load config");
+
+    assert_eq!(config.user, "admin");
+    assert_eq!(*config.password, "hunter2");
+    assert_eq!(config.port, 5432);
+
+    assert_eq!(
+        report.get("password").unwrap().provenance,
+        FieldProvenance::Found {
+            key: "db-password".to_string(),
+            origin: dir.join("db-password").display().to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_env_vars_are_fallback_for_credentials() {
+    let dir = TempDir::new("fallback");
+
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("CREDENTIALS_DIRECTORY", dir.to_str().unwrap())
+        .set("DB_USER", "admin")
+        .set("DB_PASSWORD", "from-env");
+
+    let config = DbConfig::load().expect("Failed to load config");
+
+    assert_eq!(*config.password, "from-env");
+}
+
+#[test]
+fn test_credentials_are_ignored_without_credentials_directory() {
+    let mut overrides = EnvOverrides::init();
+    overrides.set("DB_USER", "admin");
+
+    let error = DbConfig::load().expect_err("Expected a missing password");
+
+    assert!(matches!(
+        &error.field_errors[..],
+        [ConfigFieldError::MissingValue { env_vars, .. }] if env_vars == &["DB_PASSWORD"]
+    ));
+}
+
+#[test]
+fn test_credentials_source_in_layered_stack() {
+    let dir = TempDir::new("layered");
+    fs::write(dir.join("db-password"), "hunter2").unwrap();
+    fs::write(dir.join("db-port"), "not-a-port\n").unwrap();
+
+    let mut overrides = EnvOverrides::init();
+    overrides.set("DB_USER", "admin");
+
+    let source = Layered::new()
+        .layer(Credentials::from_path(&dir))
+        .layer(Env);
+
+    let error = DbConfig::load_from(&source).expect_err("Expected a parsing error");
+
+    assert!(matches!(
+        &error.field_errors[..],
+        [ConfigFieldError::ParsingError { env_var_name, raw, .. }]
+          if env_var_name == "db-port" && raw == "not-a-port"
+    ));
+}
+
+#[test]
+fn test_credentials_are_not_looked_up_by_env_names() {
+    let dir = TempDir::new("env-names");
+    fs::write(dir.join("DB_USER"), "admin").unwrap();
+    fs::write(dir.join("db-password"), "hunter2").unwrap();
+
+    let error = DbConfig::load_from(&Credentials::from_path(&dir)).expect_err("Expected errors");
+
+    assert!(matches!(
+        &error.field_errors[..],
+        [ConfigFieldError::MissingValue { field_name: Some(field_name), .. }] if field_name == "user"
+    ));
+}
+
+#[derive(Debug, Config)]
+struct TlsConfig {
+    #[credential("tls-key")]
+    #[env("TLS_KEY")]
+    key: Secret<String>,
+}
+
+#[derive(Debug, Config)]
+struct ServerConfig {
+    #[config]
+    tls: Option<TlsConfig>,
+}
+
+#[test]
+fn test_optional_nested_config_from_credentials_only() {
+    let dir = TempDir::new("optional");
+
+    let mut overrides = EnvOverrides::init();
+    overrides.set("CREDENTIALS_DIRECTORY", dir.to_str().unwrap());
+
+    let config = ServerConfig::load().expect("Failed to load config");
+    assert!(config.tls.is_none());
+
+    fs::write(dir.join("tls-key"), "private-key\n").unwrap();
+
+    let config = ServerConfig::load().expect("Failed to load config");
+    assert_eq!(
+        config.tls.as_ref().map(|tls| tls.key.as_str()),
+        Some("private-key")
+    );
+}
+
+#[test]
+fn test_missing_credentials_directory_is_reported_for_credentials() {
+    let dir = TempDir::new("missing").join("nonexistent");
+
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("CREDENTIALS_DIRECTORY", dir.to_str().unwrap())
+        .set("DB_USER", "admin")
+        .set("DB_PASSWORD", "from-env");
+
+    let error = DbConfig::load().expect_err("Expected credential errors");
+
+    match &error.field_errors[..] {
+        [
+            ConfigFieldError::Other {
+                field_name: password,
+                message,
+                ..
+            },
+            ConfigFieldError::Other {
+                field_name: port, ..
+            },
+        ] => {
+            assert_eq!(password.as_deref(), Some("password"));
+            assert_eq!(port.as_deref(), Some("port"));
+            assert!(message.contains(&format!(
+                "Failed to read credentials directory '{}'",
+                dir.display()
+            )));
+        }
+        other => panic!("Unexpected errors: {:?}", other),
+    }
+}
+
+#[derive(Debug, Config)]
+struct AppConfig {
+    #[env("APP_NAME")]
+    name: String,
+}
+
+#[test]
+fn test_missing_credentials_directory_is_ignored_without_credentials() {
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("CREDENTIALS_DIRECTORY", "/nonexistent")
+        .set("APP_NAME", "orders");
+
+    let config = AppConfig::load().expect("Failed to load config");
+
+    assert_eq!(config.name, "orders");
+}
@@ -1,4 +1,4 @@
-use tryphon::{Config, ConfigFieldError, ConfigValueDecoder, env_vars};
+use tryphon::{Config, ConfigFieldError, ConfigValueDecoder, DecodeError, DecodeOptions, env_vars};
 
 #[derive(Debug)]
 struct Point {
@@ -7,10 +7,10 @@ struct Point {
 }
 
 impl ConfigValueDecoder for Point {
-    fn decode(raw: String) -> Result<Self, String> {
+    fn decode_with(raw: &str, _options: &DecodeOptions) -> Result<Self, DecodeError> {
         let parts = raw.split('/').collect::<Vec<_>>();
         if parts.len() != 2 {
-            return Err(format!("Invalid format for Point: {}", raw));
+            return Err(format!("Invalid format for Point: {}", raw).into());
         }
         let x = parts[0].trim().parse::<i32>().map_err(|e| e.to_string())?;
         let y = parts[1].trim().parse::<i32>().map_err(|e| e.to_string())?;
@@ -73,13 +73,21 @@ fn test_derived_decoders_failure() {
         ConfigFieldError::ParsingError { raw: raw_flag, .. },
         ConfigFieldError::ParsingError { raw: raw_port, .. },
         ConfigFieldError::ParsingError {
-            raw: raw_switch, ..
+            raw: raw_switch,
+            error: switch_error,
+            ..
         },
     ] = &error.field_errors[..]
     {
         assert_eq!(raw_flag, "not_bool");
         assert_eq!(raw_port, "not_a_number");
         assert_eq!(raw_switch, "bad");
+        assert_eq!(switch_error.message(), "invalid variant 'bad'");
+        assert_eq!(switch_error.expected(), Some("one of: on, off"));
+        assert_eq!(
+            error.field_errors[2].message(),
+            Some("invalid variant 'bad'")
+        );
     } else {
         panic!("Expected parsing errors for all fields");
     }
@@ -110,5 +118,5 @@ fn test_custom_decoder() {
 fn test_custom_decoder_fail() {
     let error = Rectangle::load().expect_err("Should have failed to load config with bad values");
 
-    matches!(&error.field_errors[..], [ConfigFieldError::ParsingError { message, .. }] if message.contains("Invalid format for Point"));
+    matches!(&error.field_errors[..], [ConfigFieldError::ParsingError { error, .. }] if error.message().contains("Invalid format for Point"));
 }
@@ -0,0 +1,115 @@
+use tryphon::{Config, EnvOverrides};
+
+#[derive(Debug, Config)]
+struct ServerConfig {
+    #[env]
+    host: String,
+
+    #[env]
+    #[default(8080)]
+    max_connections: u32,
+
+    #[env("SERVER_TIMEOUT")]
+    #[env]
+    r#timeout: u64,
+}
+
+#[derive(Debug, Config)]
+#[config(rename_all = "kebab-case")]
+struct ClientConfig {
+    request_timeout: u64,
+
+    #[env("CLIENT_RETRIES")]
+    retries: u8,
+
+    #[env("client_user_agent")]
+    #[env]
+    user_agent: Option<String>,
+}
+
+#[derive(Debug, Config)]
+#[config(rename_all = "camelCase")]
+enum Storage {
+    Local { storage_path: String },
+    Remote { bucket_name: String },
+}
+
+#[test]
+fn test_bare_env_uses_screaming_snake_case() {
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("HOST", "localhost")
+        .set("MAX_CONNECTIONS", "20")
+        .set("TIMEOUT", "5");
+
+    let config = ServerConfig::load().expect("Failed to load config");
+
+    assert_eq!(config.host, "localhost");
+    assert_eq!(config.max_connections, 20);
+    assert_eq!(config.timeout, 5);
+}
+
+#[test]
+fn test_explicit_name_takes_precedence_over_derived() {
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("HOST", "localhost")
+        .set("SERVER_TIMEOUT", "10")
+        .set("TIMEOUT", "5");
+
+    let config = ServerConfig::load().expect("Failed to load config");
+
+    assert_eq!(config.timeout, 10);
+}
+
+#[test]
+fn test_rename_all() {
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("request-timeout", "30")
+        .set("CLIENT_RETRIES", "3")
+        .set("user-agent", "tryphon");
+
+    let config = ClientConfig::load().expect("Failed to load config");
+
+    assert_eq!(config.request_timeout, 30);
+    assert_eq!(config.retries, 3);
+    assert_eq!(config.user_agent.as_deref(), Some("tryphon"));
+}
+
+#[test]
+fn test_rename_all_on_enum() {
+    let mut overrides = EnvOverrides::init();
+    overrides.set("storagePath", "/var/lib/app");
+
+    match Storage::load().expect("Failed to load config") {
+        Storage::Local { storage_path } => assert_eq!(storage_path, "/var/lib/app"),
+        other => panic!("Unexpected variant: {:?}", other),
+    }
+    drop(overrides);
+
+    let mut overrides = EnvOverrides::init();
+    overrides.set("bucketName", "backups");
+
+    match Storage::load().expect("Failed to load config") {
+        Storage::Remote { bucket_name } => assert_eq!(bucket_name, "backups"),
+        other => panic!("Unexpected variant: {:?}", other),
+    }
+}
+
+#[test]
+fn test_fields_list_derived_names() {
+    let env_vars = ClientConfig::fields()
+        .into_iter()
+        .map(|field| field.env_vars)
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        env_vars,
+        vec![
+            vec!["request-timeout".to_string()],
+            vec!["CLIENT_RETRIES".to_string()],
+            vec!["client_user_agent".to_string(), "user-agent".to_string()],
+        ]
+    );
+}
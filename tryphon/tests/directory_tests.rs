@@ -0,0 +1,152 @@
+mod common;
+
+use common::TempDir;
+use std::fs;
+use tryphon::{Config, ConfigFieldError, Directory, EnvOverrides, Secret};
+
+#[derive(Debug, Config)]
+struct DbConfig {
+    #[env("DB_HOST")]
+    host: String,
+
+    #[env("DB_PORT")]
+    #[default(5432)]
+    port: u16,
+
+    #[env("DB_PASSWORD")]
+    password: Secret<String>,
+}
+
+#[test]
+fn test_load_from_directory() {
+    let dir = TempDir::new("basic");
+    fs::write(dir.join("DB_HOST"), "db.internal\n").unwrap();
+    fs::write(dir.join("DB_PASSWORD"), "hunter2").unwrap();
+
+    let config = DbConfig::load_from(&Directory::from_path(&dir)).expect("Failed to load config");
+
+    assert_eq!(config.host, "db.internal");
+    assert_eq!(config.port, 5432);
+    assert_eq!(*config.password, "hunter2");
+}
+
+#[test]
+fn test_environment_takes_precedence_over_directory() {
+    let dir = TempDir::new("layered");
+    fs::write(dir.join("DB_HOST"), "db.internal\n").unwrap();
+    fs::write(dir.join("DB_PASSWORD"), "hunter2\n").unwrap();
+
+    let mut overrides = EnvOverrides::init();
+    overrides.set("DB_HOST", "localhost");
+
+    let config = DbConfig::load_with_directory(&dir).expect("Failed to load config");
+
+    assert_eq!(config.host, "localhost");
+    assert_eq!(*config.password, "hunter2");
+}
+
+#[test]
+fn test_keys_can_be_mapped_to_file_names() {
+    let dir = TempDir::new("mapped");
+    fs::write(dir.join("db-host"), "db.internal\n").unwrap();
+    fs::write(dir.join("db-password"), "hunter2\n").unwrap();
+
+    let source = Directory::from_path(&dir).map_keys(|key| key.to_lowercase().replace('_', "-"));
+    let config = DbConfig::load_from(&source).expect("Failed to load config");
+
+    assert_eq!(config.host, "db.internal");
+}
+
+#[cfg(unix)]
+#[test]
+fn test_kubernetes_volume_layout() {
+    use std::os::unix::fs::symlink;
+
+    let dir = TempDir::new("kubernetes");
+    let timestamped = dir.join("..2024_01_01_00_00_00.000000000");
+    fs::create_dir_all(&timestamped).unwrap();
+    fs::write(timestamped.join("DB_HOST"), "db.internal\n").unwrap();
+    fs::write(timestamped.join("DB_PASSWORD"), "hunter2\n").unwrap();
+
+    symlink("..2024_01_01_00_00_00.000000000", dir.join("..data")).unwrap();
+    symlink("..data/DB_HOST", dir.join("DB_HOST")).unwrap();
+    symlink("..data/DB_PASSWORD", dir.join("DB_PASSWORD")).unwrap();
+
+    let config = DbConfig::load_from(&Directory::from_path(&dir)).expect("Failed to load config");
+
+    assert_eq!(config.host, "db.internal");
+    assert_eq!(*config.password, "hunter2");
+}
+
+#[test]
+fn test_hidden_entries_are_ignored() {
+    #[derive(Debug, Config)]
+    struct DataConfig {
+        #[env("..data")]
+        data: Option<String>,
+    }
+
+    let dir = TempDir::new("hidden");
+    fs::write(dir.join("..data"), "internal").unwrap();
+
+    let config = DataConfig::load_from(&Directory::from_path(&dir)).expect("Failed to load config");
+
+    assert_eq!(config.data, None);
+}
+
+#[test]
+fn test_files_over_size_limit_are_errors() {
+    let dir = TempDir::new("size-limit");
+    fs::write(dir.join("DB_HOST"), "db.internal").unwrap();
+    fs::write(dir.join("DB_PASSWORD"), "a".repeat(100)).unwrap();
+
+    let source = Directory::from_path(&dir).with_size_limit(64);
+    let error = DbConfig::load_from(&source).expect_err("Expected a size limit error");
+
+    let path = dir.join("DB_PASSWORD").display().to_string();
+
+    assert!(matches!(
+        &error.field_errors[..],
+        [ConfigFieldError::Other { field_name: Some(field_name), message, .. }]
+          if field_name == "password"
+            && message == &format!("File '{}' has 100 bytes, more than the limit of 64 bytes", path)
+    ));
+}
+
+#[test]
+fn test_parsing_errors_name_the_file() {
+    let dir = TempDir::new("parsing");
+    fs::write(dir.join("DB_HOST"), "db.internal").unwrap();
+    fs::write(dir.join("DB_PORT"), "not-a-port\n").unwrap();
+    fs::write(dir.join("DB_PASSWORD"), "hunter2").unwrap();
+
+    let error = DbConfig::load_from(&Directory::from_path(&dir)).expect_err("Expected an error");
+
+    let path = dir.join("DB_PORT").display().to_string();
+
+    assert!(matches!(
+        &error.field_errors[..],
+        [ConfigFieldError::ParsingError { env_var_name, raw, error, .. }]
+          if env_var_name == "DB_PORT"
+            && raw == "not-a-port"
+            && error.message().ends_with(&format!("(read from '{}')", path))
+    ));
+}
+
+#[test]
+fn test_missing_directory_is_a_source_error() {
+    let dir = std::env::temp_dir().join("tryphon-directory-does-not-exist");
+
+    let _overrides = EnvOverrides::init();
+
+    let error = DbConfig::load_with_directory(&dir).expect_err("Expected errors");
+
+    assert!(matches!(
+        &error.field_errors[..],
+        [
+            ConfigFieldError::SourceError { origin, line: None, .. },
+            ConfigFieldError::MissingValue { .. },
+            ConfigFieldError::MissingValue { .. },
+        ] if origin == &dir.display().to_string()
+    ));
+}
@@ -0,0 +1,91 @@
+mod common;
+
+use common::write_temp_file;
+use tryphon::{Config, ConfigFieldError, DotEnv, EnvOverrides, ErrorPrintMode, Secret};
+
+#[derive(Debug, Config)]
+struct AppConfig {
+    #[env("APP_NAME")]
+    name: String,
+
+    #[env("APP_PORT")]
+    #[default(8080)]
+    port: u16,
+
+    #[env("API_KEY")]
+    api_key: Secret<String>,
+}
+
+#[test]
+fn test_load_from_dotenv_file() {
+    let path = write_temp_file("basic.env", "APP_NAME=from-file\nexport API_KEY='s3cr3t'\n");
+
+    let config = AppConfig::load_from(&DotEnv::from_path(&path)).expect("Failed to load config");
+
+    assert_eq!(config.name, "from-file");
+    assert_eq!(config.port, 8080);
+    assert_eq!(*config.api_key, "s3cr3t");
+}
+
+#[test]
+fn test_later_files_override_earlier_ones() {
+    let base = write_temp_file("base.env", "APP_NAME=base\nAPP_PORT=1000\nAPI_KEY=key\n");
+    let local = write_temp_file("local.env", "APP_PORT=2000\n");
+
+    let config =
+        AppConfig::load_from(&DotEnv::from_paths([&base, &local])).expect("Failed to load config");
+
+    assert_eq!(config.name, "base");
+    assert_eq!(config.port, 2000);
+}
+
+#[test]
+fn test_environment_takes_precedence_over_dotenv() {
+    let path = write_temp_file("layered.env", "APP_NAME=from-file\nAPI_KEY=file-key\n");
+
+    let mut overrides = EnvOverrides::init();
+    overrides.set("APP_NAME", "from-env");
+
+    let config = AppConfig::load_with_dotenv([&path]).expect("Failed to load config");
+
+    assert_eq!(config.name, "from-env");
+    assert_eq!(*config.api_key, "file-key");
+}
+
+#[test]
+fn test_parse_errors_are_reported_next_to_field_errors() {
+    let path = write_temp_file("broken.env", "APP_NAME=ok\nAPP_PORT=\"8080\n");
+    let origin = path.display().to_string();
+
+    let _overrides = EnvOverrides::init();
+
+    let error = AppConfig::load_with_dotenv([&path]).expect_err("Expected errors");
+
+    assert!(matches!(
+      &error.field_errors[..],
+      [
+        ConfigFieldError::SourceError { origin: error_origin, line: Some(2), message },
+        ConfigFieldError::MissingValue { env_vars, .. },
+      ] if error_origin == &origin
+        && message == "unterminated double quote"
+        && env_vars == &["API_KEY"]
+    ));
+
+    let printed = error.pretty_print(ErrorPrintMode::List);
+    assert!(printed.contains(&format!(
+        "Error in '{}' at line 2: unterminated double quote",
+        origin
+    )));
+}
+
+#[test]
+fn test_parse_errors_fail_loading_even_if_fields_load() {
+    let dotenv = DotEnv::parse("APP_NAME=ok\nAPI_KEY=key\n???\n", ".env");
+
+    let error = AppConfig::load_from(&dotenv).expect_err("Expected a source error");
+
+    assert!(matches!(
+        &error.field_errors[..],
+        [ConfigFieldError::SourceError { line: Some(3), .. }]
+    ));
+}
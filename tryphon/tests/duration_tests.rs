@@ -0,0 +1,113 @@
+use std::time::Duration;
+use tryphon::{Config, ConfigFieldError, EnvOverrides};
+
+#[derive(Debug, Config)]
+struct HttpConfig {
+    #[env("REQUEST_TIMEOUT")]
+    #[default("30s")]
+    request_timeout: Duration,
+
+    #[env("RETRY_DELAYS", separator = ";")]
+    #[default("100ms;1s;1m30s")]
+    retry_delays: Vec<Duration>,
+
+    #[env("IDLE_TIMEOUT")]
+    idle_timeout: Option<Duration>,
+}
+
+#[derive(Debug, Config)]
+struct BrokenDefaultConfig {
+    #[env("POLL_INTERVAL")]
+    #[default("10 minutes")]
+    poll_interval: Duration,
+}
+
+#[test]
+fn test_defaults_are_decoded() {
+    let _overrides = EnvOverrides::init();
+
+    let config = HttpConfig::load().expect("Failed to load config");
+
+    assert_eq!(config.request_timeout, Duration::from_secs(30));
+    assert_eq!(
+        config.retry_delays,
+        vec![
+            Duration::from_millis(100),
+            Duration::from_secs(1),
+            Duration::from_secs(90)
+        ]
+    );
+    assert_eq!(config.idle_timeout, None);
+}
+
+#[test]
+fn test_env_values_override_defaults() {
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("REQUEST_TIMEOUT", "1500ms")
+        .set("RETRY_DELAYS", "5;2d")
+        .set("IDLE_TIMEOUT", "1h30m");
+
+    let config = HttpConfig::load().expect("Failed to load config");
+
+    assert_eq!(config.request_timeout, Duration::from_millis(1500));
+    assert_eq!(
+        config.retry_delays,
+        vec![Duration::from_secs(5), Duration::from_secs(2 * 86_400)]
+    );
+    assert_eq!(config.idle_timeout, Some(Duration::from_secs(5400)));
+}
+
+#[test]
+fn test_invalid_duration() {
+    let mut overrides = EnvOverrides::init();
+    overrides.set("REQUEST_TIMEOUT", "5w");
+
+    let error = HttpConfig::load().expect_err("Expected invalid duration");
+
+    match &error.field_errors[..] {
+        [
+            ConfigFieldError::ParsingError {
+                raw,
+                error,
+                env_var_name,
+                ..
+            },
+        ] => {
+            assert_eq!(raw, "5w");
+            assert_eq!(env_var_name, "REQUEST_TIMEOUT");
+            assert_eq!(error.message(), "unknown unit 'w' in '5w'");
+            assert_eq!(error.hint(), Some("use one of the units ms, s, m, h or d"));
+        }
+        other => panic!("Unexpected errors: {:?}", other),
+    }
+}
+
+#[test]
+fn test_invalid_default_is_reported() {
+    let mut overrides = EnvOverrides::init();
+
+    let error = BrokenDefaultConfig::load().expect_err("Expected invalid default");
+
+    match &error.field_errors[..] {
+        [
+            ConfigFieldError::Other {
+                field_name,
+                message,
+                ..
+            },
+        ] => {
+            assert_eq!(field_name.as_deref(), Some("poll_interval"));
+            assert_eq!(
+                message,
+                "invalid default value '10 minutes': unknown unit 'minutes' in '10 minutes'"
+            );
+        }
+        other => panic!("Unexpected errors: {:?}", other),
+    }
+
+    overrides.set("POLL_INTERVAL", "10m");
+
+    let config = BrokenDefaultConfig::load().expect("Failed to load config");
+    assert_eq!(config.poll_interval, Duration::from_secs(600));
+}
@@ -3,7 +3,7 @@ mod common;
 use common::TEST_MUTEX;
 use std::env;
 use std::net::Ipv4Addr;
-use tryphon::Config;
+use tryphon::{Config, ConfigFieldError, ErrorPrintMode};
 
 #[derive(Debug, PartialEq, Config)]
 enum MessagingConfig {
@@ -57,3 +57,77 @@ fn test_enum() {
         }
     );
 }
+
+#[derive(Debug, Config)]
+enum BrokerConfig {
+    Kafka {
+        #[env("KAFKA_BROKER")]
+        broker: Ipv4Addr,
+    },
+    Pulsar {
+        #[env("PULSAR_BROKER")]
+        broker: Ipv4Addr,
+    },
+}
+
+#[test]
+fn test_errors_of_every_variant_are_reported() {
+    let _unused = TEST_MUTEX.lock().unwrap();
+
+    clear_test_env_vars();
+
+    unsafe {
+        env::set_var("KAFKA_BROKER", "localhost");
+    }
+
+    let error = BrokerConfig::load().expect_err("Expected no variant to load");
+
+    unsafe {
+        env::set_var("PULSAR_BROKER", "192.168.1.1");
+    }
+
+    assert!(matches!(
+        BrokerConfig::load(),
+        Ok(BrokerConfig::Pulsar { broker }) if broker == Ipv4Addr::new(192, 168, 1, 1)
+    ));
+
+    unsafe {
+        env::set_var("KAFKA_BROKER", "127.0.0.1");
+    }
+
+    assert!(matches!(
+        BrokerConfig::load(),
+        Ok(BrokerConfig::Kafka { broker }) if broker == Ipv4Addr::new(127, 0, 0, 1)
+    ));
+
+    clear_test_env_vars();
+
+    match &error.field_errors[..] {
+        [ConfigFieldError::NoVariantLoaded { variant_errors }] => {
+            let names = variant_errors
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>();
+            assert_eq!(names, vec!["Kafka", "Pulsar"]);
+
+            assert!(matches!(
+                &variant_errors[0].1.field_errors[..],
+                [ConfigFieldError::ParsingError { env_var_name, .. }] if env_var_name == "KAFKA_BROKER"
+            ));
+            assert!(matches!(
+                &variant_errors[1].1.field_errors[..],
+                [ConfigFieldError::MissingValue { env_vars, .. }] if env_vars == &vec!["PULSAR_BROKER"]
+            ));
+        }
+        other => panic!("Unexpected errors: {:?}", other),
+    }
+
+    let printed = error.pretty_print(ErrorPrintMode::List);
+    assert!(printed.contains("Found 1 configuration error(s)"));
+    assert!(printed.contains(
+        "No variant could be loaded:\n  Kafka:\n    Parsing error for env var 'KAFKA_BROKER'"
+    ));
+    assert!(printed.contains(
+        "  Pulsar:\n    Missing value for field 'broker', tried env vars: PULSAR_BROKER"
+    ));
+}
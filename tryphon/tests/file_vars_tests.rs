@@ -0,0 +1,196 @@
+mod common;
+
+use common::write_temp_file;
+use tryphon::{Config, ConfigFieldError, EnvOverrides, ErrorPrintMode, FieldProvenance, Secret};
+
+#[derive(Debug, Config)]
+struct DbConfig {
+    #[env("DB_USER")]
+    user: String,
+
+    #[env("DB_PASSWORD", file)]
+    password: Secret<String>,
+}
+
+#[derive(Debug, Config)]
+#[config(file_vars)]
+struct ApiConfig {
+    #[env("API_TOKEN")]
+    token: Secret<String>,
+
+    #[env("API_PORT")]
+    #[default(8080)]
+    port: u16,
+
+    #[config]
+    database: DbConfig,
+}
+
+#[test]
+fn test_value_is_read_from_file() {
+    let path = write_temp_file("db_password", "s3cr3t\n");
+
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("DB_USER", "admin")
+        .set("DB_PASSWORD_FILE", path.to_str().unwrap());
+
+    let config = DbConfig::load().expect("Failed to load config");
+
+    assert_eq!(config.user, "admin");
+    assert_eq!(*config.password, "s3cr3t");
+}
+
+#[test]
+fn test_only_one_trailing_newline_is_stripped() {
+    let path = write_temp_file("multiline", "line1\nline2\n\n");
+
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("DB_USER", "admin")
+        .set("DB_PASSWORD_FILE", path.to_str().unwrap());
+
+    let config = DbConfig::load().expect("Failed to load config");
+
+    assert_eq!(*config.password, "line1\nline2\n");
+}
+
+#[test]
+fn test_inline_value_takes_precedence_over_file() {
+    let path = write_temp_file("ignored_password", "from-file");
+
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("DB_USER", "admin")
+        .set("DB_PASSWORD", "inline")
+        .set("DB_PASSWORD_FILE", path.to_str().unwrap());
+
+    let config = DbConfig::load().expect("Failed to load config");
+
+    assert_eq!(*config.password, "inline");
+}
+
+#[test]
+fn test_file_vars_are_not_checked_without_opt_in() {
+    let path = write_temp_file("db_user", "from-file");
+
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("DB_USER_FILE", path.to_str().unwrap())
+        .set("DB_PASSWORD", "inline");
+
+    let error = DbConfig::load().expect_err("Expected missing DB_USER");
+
+    assert!(matches!(
+        &error.field_errors[..],
+        [ConfigFieldError::MissingValue { env_vars, .. }] if env_vars == &["DB_USER"]
+    ));
+}
+
+#[test]
+fn test_file_vars_enabled_for_whole_struct() {
+    let token = write_temp_file("api_token", "token\n");
+    let port = write_temp_file("api_port", "9090\n");
+    let user = write_temp_file("nested_db_user", "admin\n");
+
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("API_TOKEN_FILE", token.to_str().unwrap())
+        .set("API_PORT_FILE", port.to_str().unwrap())
+        .set("DB_USER_FILE", user.to_str().unwrap())
+        .set("DB_PASSWORD", "inline");
+
+    let (config, report) = ApiConfig::load_with_report().expect("Failed to load config");
+
+    assert_eq!(*config.token, "token");
+    assert_eq!(config.port, 9090);
+    assert_eq!(config.database.user, "admin");
+    assert_eq!(*config.database.password, "inline");
+
+    let token_report = report.get("token").unwrap();
+    assert_eq!(
+        token_report.provenance,
+        FieldProvenance::Found {
+            key: "API_TOKEN_FILE".to_string(),
+            origin: token.display().to_string(),
+        }
+    );
+    assert_eq!(token_report.missing, vec!["API_TOKEN"]);
+}
+
+#[test]
+fn test_missing_value_lists_file_vars() {
+    let _overrides = EnvOverrides::init();
+
+    let error = ApiConfig::load().expect_err("Expected missing values");
+
+    assert!(matches!(
+        &error.field_errors[..],
+        [
+            ConfigFieldError::MissingValue { env_vars, .. },
+            ConfigFieldError::Nested { .. },
+        ] if env_vars == &["API_TOKEN", "API_TOKEN_FILE"]
+    ));
+}
+
+#[test]
+fn test_unreadable_file_is_reported_with_path() {
+    let path = std::env::temp_dir().join("tryphon-file-vars-does-not-exist");
+    let path = path.to_str().unwrap();
+
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("DB_USER", "admin")
+        .set("DB_PASSWORD_FILE", path);
+
+    let error = DbConfig::load().expect_err("Expected a file error");
+
+    assert!(matches!(
+        &error.field_errors[..],
+        [ConfigFieldError::FileError { field_name: Some(field_name), env_var_name, path: error_path, .. }]
+          if field_name == "password" && env_var_name == "DB_PASSWORD_FILE" && error_path == path
+    ));
+
+    let printed = error.pretty_print(ErrorPrintMode::List);
+    assert!(printed.contains(&format!(
+        "Failed to read file '{}' from env var 'DB_PASSWORD_FILE' for field 'password'",
+        path
+    )));
+}
+
+#[test]
+fn test_file_contents_are_decoded() {
+    let path = write_temp_file("invalid_port", "not-a-port\n");
+
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("API_TOKEN", "token")
+        .set("API_PORT_FILE", path.to_str().unwrap())
+        .set("DB_USER", "admin")
+        .set("DB_PASSWORD", "inline");
+
+    let error = ApiConfig::load().expect_err("Expected a parsing error");
+
+    assert!(matches!(
+        &error.field_errors[..],
+        [ConfigFieldError::ParsingError { env_var_name, raw, .. }]
+          if env_var_name == "API_PORT_FILE" && raw == "not-a-port"
+    ));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_file_size_is_limited() {
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("DB_USER", "admin")
+        .set("DB_PASSWORD_FILE", "/dev/zero");
+
+    let error = DbConfig::load().expect_err("Expected a file error");
+
+    assert!(matches!(
+        &error.field_errors[..],
+        [ConfigFieldError::FileError { env_var_name, message, .. }]
+          if env_var_name == "DB_PASSWORD_FILE" && message == "more than the limit of 1048576 bytes"
+    ));
+}
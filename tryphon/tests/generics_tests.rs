@@ -0,0 +1,76 @@
+use tryphon::{Config, ConfigValueDecoder, EnvOverrides};
+
+#[derive(Debug, Config)]
+struct PostgresConfig {
+    #[env("POSTGRES_URL")]
+    url: String,
+}
+
+#[derive(Debug, Config)]
+struct RedisConfig {
+    #[env("REDIS_URL")]
+    url: String,
+}
+
+#[derive(Debug, Config)]
+struct ServiceConfig<B> {
+    #[env("SERVICE_NAME")]
+    name: String,
+
+    #[config(prefix = "SERVICE_")]
+    backend: B,
+}
+
+#[derive(Debug, PartialEq, ConfigValueDecoder)]
+struct Limit<T>(T);
+
+#[derive(Debug, Config)]
+struct LimitsConfig<T: Copy> {
+    #[env("LIMITS")]
+    limits: Vec<Limit<T>>,
+}
+
+#[test]
+fn test_generic_nested_config() {
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("SERVICE_NAME", "orders")
+        .set("SERVICE_POSTGRES_URL", "postgres://db.internal")
+        .set("SERVICE_REDIS_URL", "redis://cache.internal");
+
+    let postgres = ServiceConfig::<PostgresConfig>::load().expect("Failed to load config");
+    let redis = ServiceConfig::<RedisConfig>::load().expect("Failed to load config");
+
+    assert_eq!(postgres.name, "orders");
+    assert_eq!(postgres.backend.url, "postgres://db.internal");
+    assert_eq!(redis.backend.url, "redis://cache.internal");
+}
+
+#[test]
+fn test_generic_decoded_values() {
+    let mut overrides = EnvOverrides::init();
+    overrides.set("LIMITS", "10,20");
+
+    let config = LimitsConfig::<u8>::load().expect("Failed to load config");
+
+    assert_eq!(config.limits, vec![Limit(10), Limit(20)]);
+}
+
+#[test]
+fn test_generic_fields_are_described() {
+    let paths = ServiceConfig::<PostgresConfig>::fields()
+        .into_iter()
+        .map(|field| (field.path.dotted_path(), field.env_vars))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        paths,
+        vec![
+            ("name".to_string(), vec!["SERVICE_NAME".to_string()]),
+            (
+                "backend.url".to_string(),
+                vec!["SERVICE_POSTGRES_URL".to_string()]
+            ),
+        ]
+    );
+}
@@ -0,0 +1,111 @@
+use tryphon::{Config, ConfigFieldError, EnvOverrides, ErrorPrintMode};
+
+#[derive(Debug, Config)]
+struct UpstreamConfig {
+    #[env("HOST")]
+    host: String,
+
+    #[env("PORT")]
+    #[default(80)]
+    port: u16,
+}
+
+#[derive(Debug, Config)]
+struct ProxyConfig {
+    #[env("LISTEN_PORT")]
+    listen_port: u16,
+
+    #[config(indexed = "UPSTREAM_")]
+    upstreams: Vec<UpstreamConfig>,
+}
+
+#[derive(Debug, Config)]
+struct AppConfig {
+    #[config(prefix = "PROXY_")]
+    proxy: ProxyConfig,
+}
+
+#[test]
+fn test_indexed_list() {
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("LISTEN_PORT", "8080")
+        .set("UPSTREAM_0_HOST", "a.internal")
+        .set("UPSTREAM_0_PORT", "8081")
+        .set("UPSTREAM_1_HOST", "b.internal")
+        .set("UPSTREAM_3_HOST", "ignored.internal");
+
+    let config = ProxyConfig::load().expect("Failed to load config");
+
+    assert_eq!(config.listen_port, 8080);
+
+    let upstreams = config
+        .upstreams
+        .iter()
+        .map(|upstream| (upstream.host.as_str(), upstream.port))
+        .collect::<Vec<_>>();
+
+    assert_eq!(upstreams, vec![("a.internal", 8081), ("b.internal", 80)]);
+}
+
+#[test]
+fn test_empty_indexed_list() {
+    let mut overrides = EnvOverrides::init();
+    overrides.set("LISTEN_PORT", "8080");
+
+    let config = ProxyConfig::load().expect("Failed to load config");
+
+    assert!(config.upstreams.is_empty());
+}
+
+#[test]
+fn test_indexed_list_with_prefix() {
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("PROXY_LISTEN_PORT", "8080")
+        .set("PROXY_UPSTREAM_0_HOST", "a.internal")
+        .set("UPSTREAM_1_HOST", "ignored.internal");
+
+    let (config, report) = AppConfig::load_with_report().expect("Failed to load config");
+
+    assert_eq!(config.proxy.upstreams.len(), 1);
+    assert_eq!(
+        report.get("proxy.upstreams[0].port").unwrap().missing,
+        vec!["PROXY_UPSTREAM_0_PORT"]
+    );
+}
+
+#[test]
+fn test_element_errors_are_nested_under_index() {
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("LISTEN_PORT", "8080")
+        .set("UPSTREAM_0_HOST", "a.internal")
+        .set("UPSTREAM_1_HOST", "b.internal")
+        .set("UPSTREAM_1_PORT", "http")
+        .set("UPSTREAM_2_PORT", "8082");
+
+    let error = ProxyConfig::load().expect_err("Expected element errors");
+
+    match &error.field_errors[..] {
+        [
+            ConfigFieldError::Nested {
+                field_name, error, ..
+            },
+        ] => {
+            assert_eq!(field_name.as_deref(), Some("upstreams"));
+            assert_eq!(error.field_errors.len(), 2);
+        }
+        other => panic!("Unexpected errors: {:?}", other),
+    }
+
+    let printed = error.pretty_print(ErrorPrintMode::List);
+    assert!(printed.contains(
+        "Parsing error for env var 'UPSTREAM_1_PORT' for field 'upstreams[1].port': invalid digit found in string"
+    ));
+    assert!(
+        printed.contains(
+            "Missing value for field 'upstreams[2].host', tried env vars: UPSTREAM_2_HOST"
+        )
+    );
+}
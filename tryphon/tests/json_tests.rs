@@ -0,0 +1,107 @@
+#![cfg(feature = "json")]
+
+mod common;
+
+use common::write_temp_file;
+use tryphon::{Config, ConfigFieldError, ConfigValueDecoder, EnvOverrides, ErrorPrintMode, Json};
+
+#[derive(Debug, PartialEq, ConfigValueDecoder)]
+enum LogLevel {
+    Info,
+    Debug,
+}
+
+#[derive(Debug, Config)]
+struct DbConfig {
+    #[env("DB_HOST")]
+    host: String,
+
+    #[env("DB_PORT")]
+    port: u16,
+}
+
+#[derive(Debug, Config)]
+struct AppConfig {
+    #[env("APP_NAME")]
+    name: String,
+
+    #[env("LOG_LEVEL")]
+    #[default(LogLevel::Info)]
+    log_level: LogLevel,
+
+    #[env("DEBUG")]
+    debug: bool,
+
+    #[config]
+    database: DbConfig,
+}
+
+const CONFIG: &str = r#"{
+  "name": "from-json",
+  "log_level": "debug",
+  "debug": true,
+  "database": {
+    "host": "db.internal",
+    "port": 5432
+  }
+}"#;
+
+#[test]
+fn test_load_from_json() {
+    let config = AppConfig::load_from(&Json::parse(CONFIG, "config.json"))
+        .expect("Failed to load config from JSON");
+
+    assert_eq!(config.name, "from-json");
+    assert_eq!(config.log_level, LogLevel::Debug);
+    assert!(config.debug);
+    assert_eq!(config.database.host, "db.internal");
+    assert_eq!(config.database.port, 5432);
+}
+
+#[test]
+fn test_env_vars_override_json_keys() {
+    let path = write_temp_file("layered.json", CONFIG);
+
+    let mut overrides = EnvOverrides::init();
+    overrides.set("DB_PORT", "6543").set("LOG_LEVEL", "info");
+
+    let config = AppConfig::load_with_json(&path).expect("Failed to load config");
+
+    assert_eq!(config.name, "from-json");
+    assert_eq!(config.log_level, LogLevel::Info);
+    assert_eq!(config.database.port, 6543);
+}
+
+#[test]
+fn test_invalid_values_are_reported_with_path() {
+    let json = Json::parse(
+        r#"{"name": "app", "log_level": "trace", "debug": false,
+            "database": {"host": {"name": "a"}, "port": 70000}}"#,
+        "config.json",
+    );
+
+    let printed = AppConfig::load_from(&json)
+        .unwrap_err()
+        .pretty_print(ErrorPrintMode::List);
+
+    assert!(printed.contains("Parsing error for env var 'log_level' for field 'log_level'"));
+    assert!(printed.contains("(raw value: trace)"));
+    assert!(printed.contains(
+        "Missing value for field 'database.host': expected a value at 'database.host' in config.json, found object"
+    ));
+    assert!(printed.contains("(raw value: 70000)"));
+}
+
+#[test]
+fn test_syntax_errors_are_reported() {
+    let path = write_temp_file("broken.json", "{\n  \"name\": \"app\",\n}\n");
+
+    let _overrides = EnvOverrides::init();
+
+    let error = AppConfig::load_with_json(&path).expect_err("Expected a syntax error");
+
+    assert!(matches!(
+        error.field_errors.first(),
+        Some(ConfigFieldError::SourceError { line: Some(3), .. })
+    ));
+}
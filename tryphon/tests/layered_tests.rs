@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+use tryphon::{
+    Config, ConfigFieldError, DotEnv, Env, EnvOverrides, ErrorPrintMode, Layered, Source,
+    SourceValue,
+};
+
+struct Fixture(HashMap<&'static str, &'static str>, &'static str);
+
+impl Source for Fixture {
+    fn lookup(&self, key: &str) -> Result<Option<SourceValue>, String> {
+        Ok(self
+            .0
+            .get(key)
+            .map(|value| SourceValue::new(*value, self.1)))
+    }
+}
+
+struct Failing;
+
+impl Source for Failing {
+    fn lookup(&self, key: &str) -> Result<Option<SourceValue>, String> {
+        Err(format!("cannot read {}", key))
+    }
+}
+
+#[derive(Debug, Config)]
+struct DbConfig {
+    #[env("DB_HOST")]
+    #[default("localhost")]
+    host: String,
+
+    #[env("DB_PORT")]
+    port: u16,
+}
+
+#[derive(Debug, Config)]
+struct AppConfig {
+    #[env("APP_PORT")]
+    #[env("PORT")]
+    port: u16,
+
+    #[env("LOG_LEVEL")]
+    #[default("info")]
+    log_level: String,
+
+    #[config]
+    database: DbConfig,
+}
+
+#[test]
+fn test_higher_layers_take_precedence() {
+    let _overrides = EnvOverrides::init();
+
+    let source = Layered::new()
+        .layer(Fixture(
+            HashMap::from([("PORT", "1000"), ("DB_HOST", "base"), ("DB_PORT", "5432")]),
+            "base",
+        ))
+        .layer(Fixture(HashMap::from([("PORT", "2000")]), "local"))
+        .layer(Env);
+
+    let config = AppConfig::load_from(&source).expect("Failed to load layered config");
+
+    assert_eq!(config.port, 2000);
+    assert_eq!(config.log_level, "info");
+    assert_eq!(config.database.host, "base");
+    assert_eq!(config.database.port, 5432);
+}
+
+#[test]
+fn test_layer_precedence_beats_fallback_order() {
+    let _overrides = EnvOverrides::init();
+
+    // The lower layer has the preferred name, the upper one only the fallback: the upper
+    // layer still wins, because every layer is searched for all names before moving down.
+    let source = Layered::new()
+        .layer(Fixture(
+            HashMap::from([("APP_PORT", "1000"), ("DB_PORT", "5432")]),
+            "base",
+        ))
+        .layer(Fixture(HashMap::from([("PORT", "2000")]), "local"));
+
+    let config = AppConfig::load_from(&source).expect("Failed to load layered config");
+
+    assert_eq!(config.port, 2000);
+}
+
+#[test]
+fn test_env_overrides_files() {
+    let mut overrides = EnvOverrides::init();
+    overrides.set("DB_PORT", "6543");
+
+    let source = Layered::new()
+        .layer(DotEnv::parse(
+            "PORT=80\nDB_PORT=5432\nLOG_LEVEL=warn",
+            ".env",
+        ))
+        .layer(DotEnv::parse("LOG_LEVEL=debug", ".env.local"))
+        .layer(Env);
+
+    let config = AppConfig::load_from(&source).expect("Failed to load layered config");
+
+    assert_eq!(config.port, 80);
+    assert_eq!(config.log_level, "debug");
+    assert_eq!(config.database.port, 6543);
+}
+
+#[test]
+fn test_layers_accept_borrowed_sources() {
+    let mut overrides = EnvOverrides::init();
+    overrides.set("PORT", "9090").set("DB_PORT", "5432");
+
+    let base = DotEnv::parse("PORT=80\nDB_HOST=db.internal", ".env");
+    let source = Layered::new().layer(&base).layer(&overrides);
+
+    let config = AppConfig::load_from(&source).expect("Failed to load layered config");
+
+    assert_eq!(config.port, 9090);
+    assert_eq!(config.database.host, "db.internal");
+}
+
+#[test]
+fn test_errors_are_aggregated_across_layers() {
+    let _overrides = EnvOverrides::init();
+
+    let source = Layered::new()
+        .layer(DotEnv::parse("PORT=\"80", "base.env"))
+        .layer(DotEnv::parse(
+            "DB_PORT=not-a-port\nINVALID LINE",
+            "local.env",
+        ))
+        .layer(Env);
+
+    let error = AppConfig::load_from(&source).expect_err("Expected errors");
+
+    assert!(matches!(
+        &error.field_errors[..],
+        [
+            ConfigFieldError::SourceError { origin: base, .. },
+            ConfigFieldError::SourceError { origin: local, .. },
+            ConfigFieldError::MissingValue { .. },
+            ConfigFieldError::Nested { .. },
+        ] if base == "base.env" && local == "local.env"
+    ));
+
+    let printed = error.pretty_print(ErrorPrintMode::List);
+    assert!(printed.contains("Parsing error for env var 'DB_PORT' for field 'database.port'"));
+}
+
+#[test]
+fn test_lookup_errors_stop_resolution() {
+    let source = Layered::new()
+        .layer(Fixture(
+            HashMap::from([("PORT", "80"), ("DB_PORT", "5432")]),
+            "base",
+        ))
+        .layer(Failing);
+
+    let error = AppConfig::load_from(&source).expect_err("Expected lookup errors");
+
+    assert!(matches!(
+        error.field_errors.first(),
+        Some(ConfigFieldError::Other { message, .. }) if message == "cannot read APP_PORT"
+    ));
+}
+
+#[test]
+fn test_empty_stack_uses_defaults() {
+    let error = AppConfig::load_from(&Layered::new()).expect_err("Expected missing values");
+
+    assert!(matches!(
+        &error.field_errors[..],
+        [ConfigFieldError::MissingValue { env_vars, .. }, ConfigFieldError::Nested { .. }]
+            if env_vars == &["APP_PORT", "PORT"]
+    ));
+}
@@ -0,0 +1,173 @@
+use tryphon::{Config, DotEnv, Env, EnvOverrides, FieldProvenance, Layered, LoadReport, Secret};
+
+#[derive(Debug, Config)]
+struct DbConfig {
+    #[env("DB_HOST")]
+    #[default("localhost")]
+    host: String,
+
+    #[env("DB_PASSWORD")]
+    password: Secret<String>,
+}
+
+#[derive(Debug, Config)]
+struct AppConfig {
+    #[env("APP_PORT")]
+    #[env("PORT")]
+    port: u16,
+
+    #[env("LOG_LEVEL")]
+    log_level: Option<String>,
+
+    #[config]
+    database: DbConfig,
+}
+
+#[derive(Debug, Config)]
+enum Backend {
+    Kafka {
+        #[env("KAFKA_BROKERS")]
+        brokers: String,
+        #[env("KAFKA_TOPIC")]
+        topic: String,
+    },
+    Redis {
+        #[env("REDIS_URL")]
+        url: String,
+    },
+}
+
+fn found(key: &str, origin: &str) -> FieldProvenance {
+    FieldProvenance::Found {
+        key: key.to_string(),
+        origin: origin.to_string(),
+    }
+}
+
+#[test]
+fn test_report_records_provenance_of_every_field() {
+    let mut overrides = EnvOverrides::init();
+    overrides.set("PORT", "9090").set("DB_PASSWORD", "hunter2");
+
+    let (config, report) = AppConfig::load_with_report().expect("Failed to load config");
+
+    assert_eq!(config.port, 9090);
+    assert_eq!(config.log_level, None);
+    assert_eq!(config.database.host, "localhost");
+    assert_eq!(config.database.password.0, "hunter2");
+
+    let port = report.get("port").unwrap();
+    assert_eq!(port.provenance, found("PORT", "override"));
+    assert_eq!(port.missing, vec!["APP_PORT"]);
+    assert!(!port.default_used());
+
+    let log_level = report.get("log_level").unwrap();
+    assert_eq!(log_level.provenance, FieldProvenance::Unset);
+    assert_eq!(log_level.missing, vec!["LOG_LEVEL"]);
+
+    let host = report.get("database.host").unwrap();
+    assert!(host.default_used());
+    assert_eq!(host.missing, vec!["DB_HOST"]);
+
+    let password = report.get("database.password").unwrap();
+    assert_eq!(password.provenance, found("DB_PASSWORD", "override"));
+    assert!(password.missing.is_empty());
+
+    assert_eq!(
+        report
+            .fields()
+            .iter()
+            .map(|field| field.path.dotted_path())
+            .collect::<Vec<_>>(),
+        vec!["port", "log_level", "database.host", "database.password"]
+    );
+}
+
+#[test]
+fn test_report_display_never_contains_values() {
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("APP_PORT", "9090")
+        .set("DB_PASSWORD", "hunter2");
+
+    let (_, report) = AppConfig::load_with_report().expect("Failed to load config");
+
+    assert_eq!(
+        report.to_string(),
+        "port: from APP_PORT (override)\n\
+         log_level: unset, missing: LOG_LEVEL\n\
+         database.host: default, missing: DB_HOST\n\
+         database.password: from DB_PASSWORD (override)\n"
+    );
+    assert!(!report.to_string().contains("hunter2"));
+}
+
+#[test]
+fn test_report_names_the_winning_layer() {
+    let mut overrides = EnvOverrides::init();
+    overrides.set("DB_HOST", "db.internal");
+
+    let source = Layered::new()
+        .layer(DotEnv::parse("PORT=80\nDB_PASSWORD=secret", ".env"))
+        .layer(Env);
+
+    let (_, report) = AppConfig::load_from_with_report(&source).expect("Failed to load config");
+
+    assert_eq!(
+        report.get("port").unwrap().provenance,
+        found("PORT", ".env:1")
+    );
+    assert_eq!(
+        report.get("database.host").unwrap().provenance,
+        found("DB_HOST", "override")
+    );
+    assert_eq!(
+        report.get("database.password").unwrap().provenance,
+        found("DB_PASSWORD", ".env:2")
+    );
+}
+
+#[test]
+fn test_report_only_contains_the_loaded_enum_variant() {
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("KAFKA_BROKERS", "localhost:9092")
+        .set("REDIS_URL", "redis://localhost");
+
+    let (config, report) = Backend::load_with_report().expect("Failed to load config");
+
+    match config {
+        Backend::Redis { url } => assert_eq!(url, "redis://localhost"),
+        Backend::Kafka { brokers, topic } => panic!("Unexpected Kafka backend {brokers}/{topic}"),
+    }
+    assert_eq!(report.fields().len(), 1);
+    assert_eq!(
+        report.get("url").unwrap().provenance,
+        found("REDIS_URL", "override")
+    );
+}
+
+#[test]
+fn test_report_is_empty_for_load_without_report() {
+    assert_eq!(LoadReport::default().fields().len(), 0);
+    assert_eq!(LoadReport::default().to_string(), "");
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn test_report_records_path_keys() {
+    let _overrides = EnvOverrides::init();
+
+    let source = Layered::new()
+        .layer(tryphon::Toml::parse(
+            "port = 8080\n[database]\npassword = \"secret\"\n",
+            "config.toml",
+        ))
+        .layer(Env);
+
+    let (_, report) = AppConfig::load_from_with_report(&source).expect("Failed to load config");
+
+    let port = report.get("port").unwrap();
+    assert_eq!(port.provenance, found("port", "config.toml"));
+    assert_eq!(port.missing, vec!["APP_PORT", "PORT"]);
+}
@@ -0,0 +1,52 @@
+use std::collections::{BTreeMap, HashMap};
+use tryphon::{Config, ConfigFieldError, EnvOverrides};
+
+#[derive(Debug, Config)]
+struct TenantsConfig {
+    #[env("TENANT_LIMITS")]
+    limits: HashMap<String, u32>,
+
+    #[env("TENANT_REGIONS", separator = ";", key_value_separator = ":")]
+    #[default(BTreeMap::new())]
+    regions: BTreeMap<String, String>,
+}
+
+#[test]
+fn test_maps() {
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("TENANT_LIMITS", "acme=100,globex=250")
+        .set("TENANT_REGIONS", "acme:eu-west-1;globex:us-east-1");
+
+    let config = TenantsConfig::load().expect("Failed to load config");
+
+    assert_eq!(
+        config.limits,
+        HashMap::from([("acme".to_string(), 100), ("globex".to_string(), 250)])
+    );
+    assert_eq!(
+        config.regions,
+        BTreeMap::from([
+            ("acme".to_string(), "eu-west-1".to_string()),
+            ("globex".to_string(), "us-east-1".to_string())
+        ])
+    );
+}
+
+#[test]
+fn test_failing_entry_is_reported() {
+    let mut overrides = EnvOverrides::init();
+    overrides.set("TENANT_LIMITS", "acme=100,globex=250,acme=50");
+
+    let error = TenantsConfig::load().expect_err("Expected a parsing error");
+
+    match &error.field_errors[..] {
+        [ConfigFieldError::ParsingError { error, .. }] => {
+            assert_eq!(
+                error.message(),
+                "duplicate key 'acme' in entry 'acme=50' at index 2"
+            );
+        }
+        other => panic!("Unexpected errors: {:?}", other),
+    }
+}
@@ -0,0 +1,192 @@
+mod common;
+
+use common::TempDir;
+use std::collections::{BTreeMap, HashMap};
+use tryphon::{
+    Config, Directory, DotEnv, Env, EnvOverrides, ErrorPrintMode, Layered, Source, SourceValue,
+};
+
+#[derive(Debug, Config)]
+struct DbConfig {
+    #[env("HOST")]
+    host: String,
+
+    #[env("PORT")]
+    #[default(5432)]
+    port: u16,
+
+    #[env("REPLICA_HOST")]
+    replica_host: Option<String>,
+}
+
+#[derive(Debug, Config)]
+struct AppConfig {
+    #[config(map = "DB_")]
+    databases: HashMap<String, DbConfig>,
+}
+
+#[derive(Debug, Config)]
+struct SortedConfig {
+    #[config(map = "DB_")]
+    databases: BTreeMap<String, DbConfig>,
+}
+
+#[derive(Debug, Config)]
+struct BackupConfig {
+    #[env("DB_BACKUP_HOST")]
+    backup_host: Option<String>,
+
+    #[config(map = "DB_")]
+    databases: BTreeMap<String, DbConfig>,
+}
+
+#[test]
+fn test_map_keys_are_discovered() {
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("DB_ORDERS_HOST", "orders.internal")
+        .set("DB_ORDERS_REPLICA_HOST", "orders-replica.internal")
+        .set("DB_BILLING_PORT", "6432")
+        .set("DB_BILLING_HOST", "billing.internal")
+        .set("DB_UNRELATED", "ignored")
+        .set("CACHE_HOST", "ignored");
+
+    let config = AppConfig::load().expect("Failed to load config");
+
+    assert_eq!(config.databases.len(), 2);
+
+    let orders = &config.databases["ORDERS"];
+    assert_eq!(orders.host, "orders.internal");
+    assert_eq!(orders.port, 5432);
+    assert_eq!(
+        orders.replica_host.as_deref(),
+        Some("orders-replica.internal")
+    );
+
+    let billing = &config.databases["BILLING"];
+    assert_eq!(billing.host, "billing.internal");
+    assert_eq!(billing.port, 6432);
+}
+
+#[test]
+fn test_empty_map() {
+    let _overrides = EnvOverrides::init();
+
+    let config = SortedConfig::load().expect("Failed to load config");
+
+    assert!(config.databases.is_empty());
+}
+
+#[test]
+fn test_entry_errors_are_nested_under_key() {
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("DB_ORDERS_HOST", "orders.internal")
+        .set("DB_ORDERS_PORT", "postgres")
+        .set("DB_BILLING_PORT", "6432");
+
+    let error = SortedConfig::load().expect_err("Expected entry errors");
+    let printed = error.pretty_print(ErrorPrintMode::List);
+
+    assert!(printed.contains(
+        "Missing value for field 'databases[BILLING].host', tried env vars: DB_BILLING_HOST"
+    ));
+    assert!(
+        printed.contains(
+            "Parsing error for env var 'DB_ORDERS_PORT' for field 'databases[ORDERS].port'"
+        )
+    );
+}
+
+#[test]
+fn test_keys_are_discovered_across_layers() {
+    let mut overrides = EnvOverrides::init();
+    overrides.set("DB_BILLING_HOST", "billing.internal");
+
+    let dotenv = DotEnv::parse("DB_ORDERS_HOST=orders.internal", ".env");
+
+    let config = SortedConfig::load_from(&Layered::new().layer(dotenv).layer(Env))
+        .expect("Failed to load config");
+
+    assert_eq!(
+        config.databases.keys().collect::<Vec<_>>(),
+        vec!["BILLING", "ORDERS"]
+    );
+}
+
+#[test]
+fn test_report_paths() {
+    let mut overrides = EnvOverrides::init();
+    overrides.set("DB_ORDERS_HOST", "orders.internal");
+
+    let (_, report) = AppConfig::load_with_report().expect("Failed to load config");
+
+    assert_eq!(
+        report.get("databases[ORDERS].port").unwrap().missing,
+        vec!["DB_ORDERS_PORT"]
+    );
+}
+
+struct Unlisted;
+
+impl Source for Unlisted {
+    fn lookup(&self, key: &str) -> Result<Option<SourceValue>, String> {
+        Ok((key == "DB_ORDERS_HOST").then(|| SourceValue::new("orders.internal", "unlisted")))
+    }
+}
+
+#[test]
+fn test_sources_without_keys_add_no_entries() {
+    let config = AppConfig::load_from(&Unlisted).expect("Failed to load config");
+
+    assert!(config.databases.is_empty());
+}
+
+#[test]
+fn test_sibling_fields_are_not_map_entries() {
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("DB_BACKUP_HOST", "backup.internal")
+        .set("DB_ORDERS_HOST", "orders.internal");
+
+    let config = BackupConfig::load().expect("Failed to load config");
+
+    assert_eq!(config.backup_host.as_deref(), Some("backup.internal"));
+    assert_eq!(config.databases.keys().collect::<Vec<_>>(), vec!["ORDERS"]);
+}
+
+#[test]
+fn test_keys_are_discovered_in_directory() {
+    let dir = TempDir::new("nested-maps");
+    dir.write("DB_ORDERS_HOST", "orders.internal\n");
+
+    let config =
+        SortedConfig::load_from(&Directory::from_path(&dir)).expect("Failed to load config");
+
+    assert_eq!(config.databases["ORDERS"].host, "orders.internal");
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn test_keys_are_discovered_in_tables() {
+    let toml = tryphon::Toml::parse(
+        r#"
+[databases.orders]
+host = "orders.internal"
+
+[databases.billing]
+host = "billing.internal"
+port = 6432
+"#,
+        "config.toml",
+    );
+
+    let config = SortedConfig::load_from(&toml).expect("Failed to load config");
+
+    assert_eq!(
+        config.databases.keys().collect::<Vec<_>>(),
+        vec!["billing", "orders"]
+    );
+    assert_eq!(config.databases["billing"].port, 6432);
+    assert_eq!(config.databases["orders"].host, "orders.internal");
+}
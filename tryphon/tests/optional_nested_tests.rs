@@ -0,0 +1,146 @@
+mod common;
+
+use common::write_temp_file;
+use tryphon::{Config, ConfigFieldError, EnvOverrides, ErrorPrintMode};
+
+#[derive(Debug, Config)]
+struct RedisConfig {
+    #[env("REDIS_HOST")]
+    host: String,
+
+    #[env("REDIS_PORT")]
+    port: u16,
+
+    #[env("REDIS_DB")]
+    #[default(0)]
+    db: u8,
+}
+
+#[derive(Debug, Config)]
+struct AppConfig {
+    #[env("APP_NAME")]
+    name: String,
+
+    #[config]
+    cache: Option<RedisConfig>,
+
+    #[config(prefix = "SESSIONS_")]
+    sessions: Option<RedisConfig>,
+}
+
+#[test]
+fn test_unconfigured_section_is_none() {
+    let mut overrides = EnvOverrides::init();
+    overrides.set("APP_NAME", "tryphon");
+
+    let config = AppConfig::load().expect("Failed to load config");
+
+    assert_eq!(config.name, "tryphon");
+    assert!(config.cache.is_none());
+    assert!(config.sessions.is_none());
+}
+
+#[test]
+fn test_configured_section_is_loaded() {
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("APP_NAME", "tryphon")
+        .set("SESSIONS_REDIS_HOST", "sessions.internal")
+        .set("SESSIONS_REDIS_PORT", "6380");
+
+    let config = AppConfig::load().expect("Failed to load config");
+
+    assert!(config.cache.is_none());
+
+    let sessions = config.sessions.expect("Expected sessions config");
+    assert_eq!(sessions.host, "sessions.internal");
+    assert_eq!(sessions.port, 6380);
+    assert_eq!(sessions.db, 0);
+}
+
+#[test]
+fn test_partially_configured_section_is_an_error() {
+    let mut overrides = EnvOverrides::init();
+    overrides.set("APP_NAME", "tryphon").set("REDIS_DB", "2");
+
+    let error = AppConfig::load().expect_err("Expected missing values");
+
+    match &error.field_errors[..] {
+        [
+            ConfigFieldError::Nested {
+                field_name, error, ..
+            },
+        ] => {
+            assert_eq!(field_name.as_deref(), Some("cache"));
+            assert!(
+                error
+                    .field_errors
+                    .iter()
+                    .all(|error| matches!(error, ConfigFieldError::MissingValue { .. }))
+            );
+        }
+        other => panic!("Unexpected errors: {:?}", other),
+    }
+
+    let printed = error.pretty_print(ErrorPrintMode::List);
+    assert!(printed.contains("Found 2 configuration error(s)"));
+    assert!(printed.contains("Missing value for field 'cache.host', tried env vars: REDIS_HOST"));
+    assert!(printed.contains("Missing value for field 'cache.port', tried env vars: REDIS_PORT"));
+}
+
+#[test]
+fn test_file_variables_are_ignored_unless_enabled() {
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("APP_NAME", "tryphon")
+        .set("REDIS_HOST_FILE", "/run/secrets/redis-host");
+
+    let config = AppConfig::load().expect("Failed to load config");
+
+    assert!(config.cache.is_none());
+}
+
+#[derive(Debug, Config)]
+#[config(file_vars)]
+struct SecretsConfig {
+    #[config]
+    cache: Option<RedisConfig>,
+}
+
+#[test]
+fn test_section_configured_with_file_variables_is_loaded() {
+    let host = write_temp_file("redis_host", "cache.internal\n");
+    let port = write_temp_file("redis_port", "6379\n");
+
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("REDIS_HOST_FILE", host.to_str().unwrap())
+        .set("REDIS_PORT_FILE", port.to_str().unwrap());
+
+    let config = SecretsConfig::load().expect("Failed to load config");
+
+    let cache = config.cache.expect("Expected cache config");
+    assert_eq!(cache.host, "cache.internal");
+    assert_eq!(cache.port, 6379);
+}
+
+#[test]
+fn test_fields_of_optional_sections_are_described() {
+    let paths = AppConfig::fields()
+        .into_iter()
+        .map(|field| field.path.dotted_path())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        paths,
+        vec![
+            "name",
+            "cache.host",
+            "cache.port",
+            "cache.db",
+            "sessions.host",
+            "sessions.port",
+            "sessions.db"
+        ]
+    );
+}
@@ -1,5 +1,7 @@
 use std::env;
-use tryphon::{Config, ConfigFieldError};
+use std::error::Error;
+use std::num::ParseIntError;
+use tryphon::{Config, ConfigFieldError, ErrorPrintMode};
 
 mod common;
 use common::TEST_MUTEX;
@@ -78,3 +80,42 @@ fn test_type_parsing_errors() {
 
     clear_test_env_vars();
 }
+
+#[test]
+fn test_parsing_errors_describe_expected_format() {
+    let _unused = TEST_MUTEX.lock().unwrap();
+
+    clear_test_env_vars();
+
+    unsafe {
+        env::set_var("DEBUG_MODE", "yes");
+        env::set_var("OPTIONAL_NUMBER", "3000000000");
+    }
+
+    let error = AppConfig::load().expect_err("Expected to fail config");
+
+    clear_test_env_vars();
+
+    match &error.field_errors[..] {
+        [
+            ConfigFieldError::ParsingError { error: debug, .. },
+            ConfigFieldError::ParsingError { error: number, .. },
+        ] => {
+            assert_eq!(debug.expected(), Some("true or false"));
+            assert_eq!(
+                number.expected(),
+                Some("an integer between -2147483648 and 2147483647")
+            );
+
+            let source = number.source().expect("Expected the ParseIntError");
+            assert_eq!(source.to_string(), "number too large to fit in target type");
+            assert!(source.downcast_ref::<ParseIntError>().is_some());
+        }
+        other => panic!("Unexpected errors: {:?}", other),
+    }
+
+    let printed = error.pretty_print(ErrorPrintMode::List);
+    assert!(printed.contains(
+        "Parsing error for env var 'DEBUG_MODE' for field 'debug': provided string was not `true` or `false` (raw value: yes)\n  expected true or false"
+    ));
+}
@@ -0,0 +1,162 @@
+mod common;
+
+use common::write_temp_file;
+use tryphon::{Config, ConfigError, ConfigFieldError, EnvOverrides, ErrorPrintMode, Secret};
+
+#[derive(Debug, Config)]
+struct DbConfig {
+    #[env("DB_HOST")]
+    host: String,
+
+    #[env("DB_PORT")]
+    #[default(5432)]
+    port: u16,
+
+    #[env("DB_PASSWORD", file)]
+    password: Option<Secret<String>>,
+}
+
+#[derive(Debug, Config)]
+struct DatabasesConfig {
+    #[config]
+    primary: DbConfig,
+
+    #[config(prefix = "REPLICA_")]
+    replica: DbConfig,
+}
+
+#[derive(Debug, Config)]
+struct AppConfig {
+    #[env("APP_NAME")]
+    name: String,
+
+    #[config(prefix = "APP_")]
+    databases: DatabasesConfig,
+}
+
+fn missing_env_vars(error: &ConfigError) -> Vec<Vec<String>> {
+    fn collect(errors: &[ConfigFieldError], acc: &mut Vec<Vec<String>>) {
+        for error in errors {
+            match error {
+                ConfigFieldError::MissingValue { env_vars, .. } => acc.push(env_vars.clone()),
+                ConfigFieldError::Nested { error, .. } => collect(&error.field_errors, acc),
+                _ => {}
+            }
+        }
+    }
+
+    let mut acc = Vec::new();
+    collect(&error.field_errors, &mut acc);
+    acc
+}
+
+#[test]
+fn test_nested_prefix() {
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("DB_HOST", "primary.internal")
+        .set("REPLICA_DB_HOST", "replica.internal")
+        .set("REPLICA_DB_PORT", "6432");
+
+    let config = DatabasesConfig::load().expect("Failed to load config");
+
+    assert_eq!(config.primary.host, "primary.internal");
+    assert_eq!(config.primary.port, 5432);
+    assert_eq!(config.replica.host, "replica.internal");
+    assert_eq!(config.replica.port, 6432);
+}
+
+#[test]
+fn test_prefixes_stack() {
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("APP_NAME", "tryphon")
+        .set("APP_DB_HOST", "primary.internal")
+        .set("APP_REPLICA_DB_HOST", "replica.internal")
+        .set("DB_HOST", "ignored")
+        .set("REPLICA_DB_HOST", "ignored");
+
+    let config = AppConfig::load().expect("Failed to load config");
+
+    assert_eq!(config.databases.primary.host, "primary.internal");
+    assert_eq!(config.databases.replica.host, "replica.internal");
+}
+
+#[test]
+fn test_load_with_prefix() {
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("STAGING_APP_NAME", "tryphon")
+        .set("STAGING_APP_DB_HOST", "primary.internal")
+        .set("STAGING_APP_REPLICA_DB_HOST", "replica.internal");
+
+    let config = AppConfig::load_with_prefix("STAGING_").expect("Failed to load config");
+
+    assert_eq!(config.name, "tryphon");
+    assert_eq!(config.databases.replica.host, "replica.internal");
+}
+
+#[test]
+fn test_missing_values_list_prefixed_names() {
+    let _overrides = EnvOverrides::init();
+
+    let error = AppConfig::load().expect_err("Expected missing values");
+
+    assert_eq!(
+        missing_env_vars(&error),
+        vec![
+            vec!["APP_NAME"],
+            vec!["APP_DB_HOST"],
+            vec!["APP_REPLICA_DB_HOST"],
+        ]
+    );
+
+    let printed = error.pretty_print(ErrorPrintMode::List);
+    assert!(printed.contains(
+        "Missing value for field 'databases.replica.host', tried env vars: APP_REPLICA_DB_HOST"
+    ));
+}
+
+#[test]
+fn test_file_vars_are_prefixed() {
+    let path = write_temp_file("replica_db_password", "hunter2\n");
+
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("DB_HOST", "primary.internal")
+        .set("REPLICA_DB_HOST", "replica.internal")
+        .set("REPLICA_DB_PASSWORD_FILE", path.to_str().unwrap());
+
+    let (config, report) = DatabasesConfig::load_with_report().expect("Failed to load config");
+
+    assert!(config.primary.password.is_none());
+    assert_eq!(**config.replica.password.as_ref().unwrap(), "hunter2");
+    assert_eq!(
+        report.get("replica.password").unwrap().missing,
+        vec!["REPLICA_DB_PASSWORD"]
+    );
+}
+
+#[test]
+fn test_fields_list_prefixed_names() {
+    let env_vars = AppConfig::fields()
+        .into_iter()
+        .map(|field| (field.path.dotted_path(), field.env_vars))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        env_vars[..3],
+        [
+            ("name".to_string(), vec!["APP_NAME".to_string()]),
+            (
+                "databases.primary.host".to_string(),
+                vec!["APP_DB_HOST".to_string()]
+            ),
+            (
+                "databases.primary.port".to_string(),
+                vec!["APP_DB_PORT".to_string()]
+            ),
+        ]
+    );
+    assert_eq!(env_vars[5].1, vec!["APP_REPLICA_DB_PORT"]);
+}
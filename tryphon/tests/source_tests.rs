@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+use tryphon::{Config, ConfigFieldError, Env, EnvOverrides, Source, SourceValue, env_vars};
+
+struct Fixture(HashMap<&'static str, &'static str>);
+
+impl Source for Fixture {
+    fn lookup(&self, key: &str) -> Result<Option<SourceValue>, String> {
+        Ok(self
+            .0
+            .get(key)
+            .map(|value| SourceValue::new(*value, "fixture")))
+    }
+}
+
+struct FailingSource;
+
+impl Source for FailingSource {
+    fn lookup(&self, key: &str) -> Result<Option<SourceValue>, String> {
+        Err(format!("cannot read {}", key))
+    }
+}
+
+#[derive(Debug, Config)]
+struct DbConfig {
+    #[env("DB_HOST")]
+    #[default("localhost")]
+    host: String,
+}
+
+#[derive(Debug, Config)]
+struct AppConfig {
+    #[env("APP_PORT")]
+    #[env("PORT")]
+    port: u16,
+
+    #[config]
+    database: DbConfig,
+}
+
+#[test]
+fn test_load_from_custom_source() {
+    let fixture = Fixture(HashMap::from([("PORT", "9090"), ("DB_HOST", "db.local")]));
+
+    let config = AppConfig::load_from(&fixture).expect("Failed to load config from fixture");
+
+    assert_eq!(config.port, 9090);
+    assert_eq!(config.database.host, "db.local");
+}
+
+#[test]
+fn test_load_from_custom_source_missing_value() {
+    let fixture = Fixture(HashMap::new());
+
+    let error = AppConfig::load_from(&fixture).expect_err("Expected missing port");
+
+    assert!(matches!(
+      &error.field_errors[..],
+      [ConfigFieldError::MissingValue { env_vars, .. }] if env_vars == &["APP_PORT", "PORT"]
+    ));
+}
+
+#[test]
+fn test_source_errors_are_reported() {
+    let error = AppConfig::load_from(&FailingSource).expect_err("Expected lookup errors");
+
+    assert_eq!(error.field_errors.len(), 2);
+    assert!(matches!(
+      &error.field_errors[0],
+      ConfigFieldError::Other { message, .. } if message == "cannot read APP_PORT"
+    ));
+}
+
+#[test]
+#[env_vars(PORT = "8000")]
+fn test_load_from_env_respects_overrides() {
+    let config = AppConfig::load_from(&Env).expect("Failed to load config from env");
+
+    assert_eq!(config.port, 8000);
+    assert_eq!(
+        Env.lookup("PORT").unwrap(),
+        Some(SourceValue::new("8000", "override"))
+    );
+}
+
+#[test]
+fn test_env_overrides_as_source() {
+    let mut overrides = EnvOverrides::init();
+    overrides.set("APP_PORT", "7000");
+
+    let config = AppConfig::load_from(&overrides).expect("Failed to load config from overrides");
+
+    assert_eq!(config.port, 7000);
+    assert_eq!(config.database.host, "localhost");
+    assert_eq!(overrides.lookup("DB_HOST").unwrap(), None);
+}
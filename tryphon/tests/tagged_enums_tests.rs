@@ -0,0 +1,152 @@
+use std::net::Ipv4Addr;
+use tryphon::{Config, ConfigFieldError, EnvOverrides, ErrorPrintMode};
+
+#[derive(Debug, PartialEq, Config)]
+#[config(tag = "MESSAGING_BACKEND")]
+enum MessagingConfig {
+    #[variant("kafka", "confluent")]
+    Kafka {
+        #[env("KAFKA_BROKER")]
+        broker: Ipv4Addr,
+    },
+    Pulsar {
+        #[env("PULSAR_BROKER")]
+        broker: Ipv4Addr,
+    },
+    Mock {
+        #[env("MOCK_BROKER")]
+        #[default(Ipv4Addr::new(1, 1, 1, 1))]
+        broker: Ipv4Addr,
+    },
+}
+
+#[derive(Debug, Config)]
+struct AppConfig {
+    #[config(prefix = "APP_")]
+    messaging: MessagingConfig,
+}
+
+#[test]
+fn test_tag_selects_variant() {
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("MESSAGING_BACKEND", "Pulsar")
+        .set("KAFKA_BROKER", "127.0.0.1")
+        .set("PULSAR_BROKER", "192.168.1.1");
+
+    assert_eq!(
+        MessagingConfig::load().unwrap(),
+        MessagingConfig::Pulsar {
+            broker: Ipv4Addr::new(192, 168, 1, 1)
+        }
+    );
+}
+
+#[test]
+fn test_tag_matches_aliases_case_insensitively() {
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("MESSAGING_BACKEND", "CONFLUENT")
+        .set("KAFKA_BROKER", "127.0.0.1");
+
+    assert_eq!(
+        MessagingConfig::load().unwrap(),
+        MessagingConfig::Kafka {
+            broker: Ipv4Addr::new(127, 0, 0, 1)
+        }
+    );
+}
+
+#[test]
+fn test_selected_variant_errors_are_not_masked() {
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("MESSAGING_BACKEND", "kafka")
+        .set("KAFKA_BROKR", "127.0.0.1");
+
+    let error = MessagingConfig::load().expect_err("Expected missing Kafka broker");
+
+    match &error.field_errors[..] {
+        [
+            ConfigFieldError::MissingValue {
+                field_name,
+                env_vars,
+                ..
+            },
+        ] => {
+            assert_eq!(field_name.as_deref(), Some("broker"));
+            assert_eq!(env_vars, &vec!["KAFKA_BROKER"]);
+        }
+        other => panic!("Unexpected errors: {:?}", other),
+    }
+}
+
+#[test]
+fn test_tag_is_prefixed() {
+    let mut overrides = EnvOverrides::init();
+    overrides
+        .set("MESSAGING_BACKEND", "kafka")
+        .set("APP_MESSAGING_BACKEND", "mock");
+
+    let config = AppConfig::load().expect("Failed to load config");
+
+    assert_eq!(
+        config.messaging,
+        MessagingConfig::Mock {
+            broker: Ipv4Addr::new(1, 1, 1, 1)
+        }
+    );
+}
+
+#[test]
+fn test_missing_tag() {
+    let _overrides = EnvOverrides::init();
+
+    let error = MessagingConfig::load().expect_err("Expected missing tag");
+
+    match &error.field_errors[..] {
+        [
+            ConfigFieldError::MissingValue {
+                field_name,
+                env_vars,
+                ..
+            },
+        ] => {
+            assert_eq!(field_name.as_deref(), Some("tag"));
+            assert_eq!(env_vars, &vec!["MESSAGING_BACKEND"]);
+        }
+        other => panic!("Unexpected errors: {:?}", other),
+    }
+}
+
+#[test]
+fn test_unknown_tag() {
+    let mut overrides = EnvOverrides::init();
+    overrides.set("APP_MESSAGING_BACKEND", "rabbitmq");
+
+    let error = AppConfig::load().expect_err("Expected unknown tag");
+
+    let printed = error.pretty_print(ErrorPrintMode::List);
+    assert!(printed.contains("Found 1 configuration error(s)"));
+    assert!(printed.contains(
+        "unknown variant 'rabbitmq' (raw value: rabbitmq)\n  expected one of: kafka, Pulsar, Mock"
+    ));
+    assert!(printed.contains("APP_MESSAGING_BACKEND"));
+}
+
+#[test]
+fn test_tag_is_described() {
+    let fields = AppConfig::fields()
+        .into_iter()
+        .map(|field| (field.path.dotted_path(), field.env_vars))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        fields[0],
+        (
+            "messaging.tag".to_string(),
+            vec!["APP_MESSAGING_BACKEND".to_string()]
+        )
+    );
+    assert_eq!(fields.len(), 4);
+}
@@ -0,0 +1,181 @@
+#![cfg(feature = "toml")]
+
+mod common;
+
+use common::write_temp_file;
+use tryphon::{Config, ConfigFieldError, EnvOverrides, ErrorPrintMode, Toml};
+
+#[derive(Debug, Config)]
+struct PoolConfig {
+    #[env("DB_POOL_SIZE")]
+    #[default(4)]
+    size: u32,
+}
+
+#[derive(Debug, Config)]
+struct DbConfig {
+    #[env("DB_HOST")]
+    host: String,
+
+    #[env("DB_PORT")]
+    port: u16,
+
+    #[config]
+    pool: PoolConfig,
+}
+
+#[derive(Debug, Config)]
+struct AppConfig {
+    #[env("APP_NAME")]
+    name: String,
+
+    #[env("LOG_LEVEL")]
+    log_level: Option<String>,
+
+    #[config]
+    database: DbConfig,
+}
+
+const CONFIG: &str = r#"
+name = "from-toml"
+
+[database]
+host = "db.internal"
+port = 5432
+
+[database.pool]
+size = 16
+"#;
+
+#[test]
+fn test_load_from_toml() {
+    let config = AppConfig::load_from(&Toml::parse(CONFIG, "config.toml"))
+        .expect("Failed to load config from TOML");
+
+    assert_eq!(config.name, "from-toml");
+    assert_eq!(config.log_level, None);
+    assert_eq!(config.database.host, "db.internal");
+    assert_eq!(config.database.port, 5432);
+    assert_eq!(config.database.pool.size, 16);
+}
+
+#[test]
+fn test_env_vars_override_toml_keys() {
+    let path = write_temp_file("layered.toml", CONFIG);
+
+    let mut overrides = EnvOverrides::init();
+    overrides.set("DB_PORT", "6543").set("LOG_LEVEL", "debug");
+
+    let config = AppConfig::load_with_toml(&path).expect("Failed to load config");
+
+    assert_eq!(config.name, "from-toml");
+    assert_eq!(config.log_level, Some("debug".to_string()));
+    assert_eq!(config.database.host, "db.internal");
+    assert_eq!(config.database.port, 6543);
+}
+
+#[test]
+fn test_type_mismatches_are_reported_with_table_path() {
+    let toml = Toml::parse(
+        r#"
+name = "app"
+
+[database]
+host = "db"
+port = "not-a-port"
+pool = 8
+"#,
+        "config.toml",
+    );
+
+    let error = AppConfig::load_from(&toml).expect_err("Expected errors");
+
+    let [ConfigFieldError::Nested { error, .. }] = &error.field_errors[..] else {
+        panic!("Expected a nested error, got {:?}", error);
+    };
+
+    assert!(matches!(
+      &error.field_errors[..],
+      [
+        ConfigFieldError::ParsingError { env_var_name, raw, .. },
+        ConfigFieldError::Nested { .. },
+      ] if env_var_name == "database.port" && raw == "not-a-port"
+    ));
+
+    let printed = AppConfig::load_from(&toml)
+        .unwrap_err()
+        .pretty_print(ErrorPrintMode::List);
+
+    assert!(printed.contains(
+        "Missing value for field 'database.pool.size': expected a table at 'database.pool' in config.toml, found integer"
+    ));
+}
+
+#[test]
+fn test_missing_keys_fall_back_to_missing_value() {
+    let toml = Toml::parse("name = \"app\"\n[database]\nhost = \"db\"\n", "config.toml");
+
+    let error = AppConfig::load_from(&toml).expect_err("Expected missing port");
+
+    let printed = error.pretty_print(ErrorPrintMode::List);
+    assert!(printed.contains("Missing value for field 'database.port', tried env vars: DB_PORT"));
+}
+
+#[test]
+fn test_syntax_errors_are_reported() {
+    let path = write_temp_file("broken.toml", "name = \"app\"\n[database\n");
+
+    let _overrides = EnvOverrides::init();
+
+    let error = AppConfig::load_with_toml(&path).expect_err("Expected a syntax error");
+
+    assert!(matches!(
+        error.field_errors.first(),
+        Some(ConfigFieldError::SourceError { line: Some(2), .. })
+    ));
+}
+
+#[derive(Debug, Config)]
+struct UpstreamConfig {
+    #[env("HOST")]
+    host: String,
+
+    #[env("PORT")]
+    #[default(80)]
+    port: u16,
+}
+
+#[derive(Debug, Config)]
+struct ProxyConfig {
+    #[env("ALLOWED_HOSTS")]
+    allowed_hosts: Vec<String>,
+
+    #[config(indexed = "UPSTREAM_")]
+    upstreams: Vec<UpstreamConfig>,
+}
+
+#[test]
+fn test_arrays_are_read_as_lists() {
+    let toml = Toml::parse(
+        r#"
+allowed_hosts = ["example.com", "a,b.example.com"]
+
+[[upstreams]]
+host = "one.internal"
+
+[[upstreams]]
+host = "two.internal"
+port = 8080
+"#,
+        "config.toml",
+    );
+
+    let config = ProxyConfig::load_from(&toml).expect("Failed to load config from TOML");
+
+    assert_eq!(config.allowed_hosts, vec!["example.com", "a,b.example.com"]);
+    assert_eq!(config.upstreams.len(), 2);
+    assert_eq!(config.upstreams[0].host, "one.internal");
+    assert_eq!(config.upstreams[0].port, 80);
+    assert_eq!(config.upstreams[1].host, "two.internal");
+    assert_eq!(config.upstreams[1].port, 8080);
+}
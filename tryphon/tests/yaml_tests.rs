@@ -0,0 +1,112 @@
+#![cfg(feature = "yaml")]
+
+mod common;
+
+use common::write_temp_file;
+use tryphon::{Config, ConfigFieldError, ConfigValueDecoder, EnvOverrides, ErrorPrintMode, Yaml};
+
+#[derive(Debug, PartialEq, ConfigValueDecoder)]
+enum LogLevel {
+    Info,
+    Debug,
+}
+
+#[derive(Debug, Config)]
+struct DbConfig {
+    #[env("DB_HOST")]
+    host: String,
+
+    #[env("DB_PORT")]
+    port: u16,
+}
+
+#[derive(Debug, Config)]
+struct AppConfig {
+    #[env("APP_NAME")]
+    name: String,
+
+    #[env("LOG_LEVEL")]
+    #[default(LogLevel::Info)]
+    log_level: LogLevel,
+
+    #[env("RATIO")]
+    ratio: f64,
+
+    #[config]
+    database: DbConfig,
+}
+
+const CONFIG: &str = r#"
+name: from-yaml
+log_level: debug
+ratio: 0.25
+database:
+  host: db.internal
+  port: 5432
+"#;
+
+#[test]
+fn test_load_from_yaml() {
+    let config = AppConfig::load_from(&Yaml::parse(CONFIG, "config.yaml"))
+        .expect("Failed to load config from YAML");
+
+    assert_eq!(config.name, "from-yaml");
+    assert_eq!(config.log_level, LogLevel::Debug);
+    assert_eq!(config.ratio, 0.25);
+    assert_eq!(config.database.host, "db.internal");
+    assert_eq!(config.database.port, 5432);
+}
+
+#[test]
+fn test_env_vars_override_yaml_keys() {
+    let path = write_temp_file("layered.yaml", CONFIG);
+
+    let mut overrides = EnvOverrides::init();
+    overrides.set("DB_HOST", "replica").set("LOG_LEVEL", "info");
+
+    let config = AppConfig::load_with_yaml(&path).expect("Failed to load config");
+
+    assert_eq!(config.name, "from-yaml");
+    assert_eq!(config.log_level, LogLevel::Info);
+    assert_eq!(config.database.host, "replica");
+    assert_eq!(config.database.port, 5432);
+}
+
+#[test]
+fn test_null_values_are_missing() {
+    let yaml = Yaml::parse(
+        "name: app\nratio: 1\ndatabase:\n  host: ~\n  port: 5432\n",
+        "config.yaml",
+    );
+
+    let printed = AppConfig::load_from(&yaml)
+        .unwrap_err()
+        .pretty_print(ErrorPrintMode::List);
+
+    assert!(printed.contains("Missing value for field 'database.host', tried env vars: DB_HOST"));
+}
+
+#[test]
+fn test_type_mismatches_are_reported_with_path() {
+    let yaml = Yaml::parse("name: app\nratio: 1\ndatabase: [1, 2]\n", "config.yaml");
+
+    let printed = AppConfig::load_from(&yaml)
+        .unwrap_err()
+        .pretty_print(ErrorPrintMode::List);
+
+    assert!(printed.contains("expected a mapping at 'database' in config.yaml, found sequence"));
+}
+
+#[test]
+fn test_syntax_errors_are_reported() {
+    let path = write_temp_file("broken.yaml", "name: app\ndatabase: [unclosed\n");
+
+    let _overrides = EnvOverrides::init();
+
+    let error = AppConfig::load_with_yaml(&path).expect_err("Expected a syntax error");
+
+    assert!(matches!(
+        error.field_errors.first(),
+        Some(ConfigFieldError::SourceError { line: Some(_), .. })
+    ));
+}
@@ -0,0 +1,107 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{Error, Expr, ExprAssign, ExprLit, Lit, Type};
+
+/// Decoding options of a field, set with `#[env]` options like `separator = ";"` and passed
+/// to `ConfigValueDecoder::decode_with`.
+#[derive(Default)]
+pub(crate) struct DecodeOptionsAttrs {
+    separator: Option<String>,
+    key_value_separator: Option<String>,
+    byte_size: bool,
+}
+
+impl DecodeOptionsAttrs {
+    /// Names of the supported options, for error messages.
+    pub(crate) const NAMES: &'static str =
+        "`separator = \"...\"`, `key_value_separator = \"...\"` or `byte_size`";
+
+    /// Parses a `name = "value"` or flag option of the `#[env]` attribute, returning `false`
+    /// if it's not a decoding option.
+    pub(crate) fn parse_option(
+        &mut self,
+        option: &Expr,
+        compile_errors_stream: &mut TokenStream,
+    ) -> bool {
+        if let Expr::Path(name) = option
+            && name.path.is_ident("byte_size")
+        {
+            self.byte_size = true;
+            return true;
+        }
+
+        let Expr::Assign(ExprAssign { left, right, .. }) = option else {
+            return false;
+        };
+
+        let Expr::Path(name) = left.as_ref() else {
+            return false;
+        };
+
+        let slot = if name.path.is_ident("separator") {
+            &mut self.separator
+        } else if name.path.is_ident("key_value_separator") {
+            &mut self.key_value_separator
+        } else {
+            return false;
+        };
+
+        match right.as_ref() {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(token),
+                ..
+            }) if !token.value().is_empty() => {
+                if slot.as_ref().is_some_and(|value| *value != token.value()) {
+                    let error_stream: TokenStream =
+                        Error::new(option.span(), "Conflicting values of the option")
+                            .to_compile_error()
+                            .into();
+                    compile_errors_stream.extend(error_stream);
+                } else {
+                    *slot = Some(token.value());
+                }
+            }
+            other => {
+                let error_stream: TokenStream =
+                    Error::new(other.span(), "Expecting a non-empty string literal")
+                        .to_compile_error()
+                        .into();
+                compile_errors_stream.extend(error_stream);
+            }
+        }
+
+        true
+    }
+
+    /// Builds the `tryphon::DecodeOptions` expression passed to the decoder.
+    pub(crate) fn to_tokens(&self) -> TokenStream2 {
+        let separator = self.separator.as_ref().map(|separator| {
+            quote! { .with_separator(#separator) }
+        });
+
+        let key_value_separator = self.key_value_separator.as_ref().map(|separator| {
+            quote! { .with_key_value_separator(#separator) }
+        });
+
+        let byte_size = self.byte_size.then(|| quote! { .with_byte_size(true) });
+
+        quote! { tryphon::DecodeOptions::default() #separator #key_value_separator #byte_size }
+    }
+
+    /// Builds a statement failing to compile if `field_type` doesn't support the `byte_size`
+    /// option, which would otherwise be ignored by its decoder.
+    pub(crate) fn to_checks(&self, field_type: &Type) -> TokenStream2 {
+        if !self.byte_size {
+            return TokenStream2::new();
+        }
+
+        quote_spanned! { field_type.span() =>
+          {
+            fn supports_byte_size<T: tryphon::ByteSizeDecoder>() {}
+            supports_byte_size::<#field_type>();
+          }
+        }
+    }
+}
@@ -1,28 +1,64 @@
+mod decode_options;
+mod rename_rule;
 mod struct_type;
 mod utils;
 
+use crate::decode_options::DecodeOptionsAttrs;
+use crate::rename_rule::RenameRule;
 use crate::struct_type::StructType;
-use crate::utils::{ident_opt_to_str, is_option};
+use crate::utils::{field_segment, ident_opt_to_str, is_option, option_inner_type, type_mentions};
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
 use proc_macro2::{Ident, Span};
 use quote::quote;
+use syn::ext::IdentExt;
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::{
-    Data, DeriveInput, Error, Expr, ExprLit, Field, ItemFn, Lit, Meta, Path, Token, Type,
-    parse_macro_input,
+    Attribute, Data, DeriveInput, Error, Expr, ExprLit, Field, Generics, ItemFn, Lit, LitStr, Meta,
+    Path, Token, Type, Variant, WherePredicate, parse_macro_input, parse_quote,
 };
 
-fn find_attrs(field: &Field, compile_errors_stream: &mut TokenStream) -> (Vec<String>, bool) {
+struct FieldAttrs {
+    env_vars: Vec<String>,
+    file_vars: Vec<String>,
+    credentials: Vec<String>,
+    decode_options: DecodeOptionsAttrs,
+    is_nested_config: bool,
+    nested_kind: NestedKind,
+}
+
+fn derived_env_name(field: &Field, rule: RenameRule) -> Option<String> {
+    field
+        .ident
+        .as_ref()
+        .map(|ident| rule.apply(&ident.unraw().to_string()))
+}
+
+fn find_attrs(
+    field: &Field,
+    rename_all: Option<RenameRule>,
+    compile_errors_stream: &mut TokenStream,
+) -> FieldAttrs {
     let mut loaders: Vec<String> = Vec::new();
+    let mut file_vars: Vec<String> = Vec::new();
+    let mut credentials: Vec<String> = Vec::new();
+    let mut decode_options = DecodeOptionsAttrs::default();
 
     let mut is_nested_config = false;
+    let mut nested_kind = NestedKind::Single(None);
+    // Set when a bare #[env] can't derive a name, which is already reported, so the field
+    // isn't also reported as having no #[env] attribute.
+    let mut derive_failed = false;
 
     for attr in &field.attrs {
         if attr.path().is_ident("config") {
             is_nested_config = true;
-        } else if attr.path().is_ident("env") {
+
+            if let Meta::List(_) = attr.meta {
+                nested_kind = find_nested_kind(attr, compile_errors_stream);
+            }
+        } else if attr.path().is_ident("credential") {
             match attr.parse_args::<Expr>() {
                 Ok(Expr::Lit(ExprLit {
                     lit: Lit::Str(token),
@@ -30,16 +66,16 @@ fn find_attrs(field: &Field, compile_errors_stream: &mut TokenStream) -> (Vec<St
                 })) => {
                     let value = token.value().trim().to_string();
 
-                    if value.is_empty() {
+                    if value.is_empty() || value.starts_with('.') || value.contains(['/', '\\']) {
                         let error_stream: TokenStream = Error::new(
                             attr.meta.path().span(),
-                            "Environment variable can't be empty nor blank",
+                            "Credential name must be a non-empty file name",
                         )
                         .to_compile_error()
                         .into();
                         compile_errors_stream.extend(error_stream);
                     } else {
-                        loaders.push(value);
+                        credentials.push(value);
                     }
                 }
                 _ => {
@@ -50,26 +86,359 @@ fn find_attrs(field: &Field, compile_errors_stream: &mut TokenStream) -> (Vec<St
                     compile_errors_stream.extend(error_stream);
                 }
             }
+        } else if attr.path().is_ident("env") {
+            let args = match attr.meta {
+                Meta::Path(_) => Punctuated::new(),
+                _ => match attr.parse_args_with(Punctuated::<Expr, Token![,]>::parse_terminated) {
+                    Ok(args) => args,
+                    Err(_) => {
+                        let error_stream: TokenStream =
+                            Error::new(attr.meta.path().span(), "Expecting a string literal")
+                                .to_compile_error()
+                                .into();
+                        compile_errors_stream.extend(error_stream);
+                        continue;
+                    }
+                },
+            };
+
+            let mut args = args.into_iter().peekable();
+
+            let value = match args.peek() {
+                Some(Expr::Lit(ExprLit {
+                    lit: Lit::Str(token),
+                    ..
+                })) => {
+                    let value = token.value().trim().to_string();
+                    args.next();
+
+                    if value.is_empty() {
+                        let error_stream: TokenStream = Error::new(
+                            attr.meta.path().span(),
+                            "Environment variable can't be empty nor blank",
+                        )
+                        .to_compile_error()
+                        .into();
+                        compile_errors_stream.extend(error_stream);
+                        continue;
+                    }
+
+                    value
+                }
+                Some(Expr::Path(_) | Expr::Assign(_)) | None => {
+                    match derived_env_name(field, rename_all.unwrap_or(RenameRule::ScreamingSnake))
+                    {
+                        Some(value) => value,
+                        None => {
+                            let error_stream: TokenStream = Error::new(
+                                attr.meta.path().span(),
+                                "Can't derive environment variable name for a field without a name, use #[env(\"NAME\")]",
+                            )
+                            .to_compile_error()
+                            .into();
+                            compile_errors_stream.extend(error_stream);
+                            derive_failed = true;
+                            continue;
+                        }
+                    }
+                }
+                Some(_) => {
+                    let error_stream: TokenStream =
+                        Error::new(attr.meta.path().span(), "Expecting a string literal")
+                            .to_compile_error()
+                            .into();
+                    compile_errors_stream.extend(error_stream);
+                    continue;
+                }
+            };
+
+            for option in args {
+                match option {
+                    Expr::Path(ref path) if path.path.is_ident("file") => {
+                        file_vars.push(value.clone());
+                    }
+                    _ if decode_options.parse_option(&option, compile_errors_stream) => {}
+                    _ => {
+                        let error_stream: TokenStream = Error::new(
+                            option.span(),
+                            format!(
+                                "Unknown env option, expected `file`, {}",
+                                DecodeOptionsAttrs::NAMES
+                            ),
+                        )
+                        .to_compile_error()
+                        .into();
+                        compile_errors_stream.extend(error_stream);
+                    }
+                }
+            }
+
+            loaders.push(value);
         }
     }
 
-    if loaders.is_empty() && !is_nested_config {
+    if loaders.is_empty()
+        && !is_nested_config
+        && let Some(value) = rename_all.and_then(|rule| derived_env_name(field, rule))
+    {
+        loaders.push(value);
+    }
+
+    if loaders.is_empty() && !is_nested_config && !derive_failed {
         let error_stream: TokenStream = Error::new(field.span(), "No env attribute found")
             .to_compile_error()
             .into();
         compile_errors_stream.extend(error_stream);
-    } else if is_nested_config && !loaders.is_empty() {
+    } else if is_nested_config && (!loaders.is_empty() || !credentials.is_empty()) {
         let error_stream: TokenStream = Error::new(field.span(), "You can either mark field as nested config or provide env variables to read from, not both.").to_compile_error().into();
         compile_errors_stream.extend(error_stream);
     }
 
-    (loaders, is_nested_config)
+    FieldAttrs {
+        env_vars: loaders,
+        file_vars,
+        credentials,
+        decode_options,
+        is_nested_config,
+        nested_kind,
+    }
+}
+
+/// How a field marked with `#[config]` is loaded.
+enum NestedKind {
+    /// A single nested configuration, with the prefix set by `#[config(prefix = "...")]`.
+    Single(Option<String>),
+    /// A list of nested configurations, one for each index found after the prefix set by
+    /// `#[config(indexed = "...")]`.
+    Indexed(String),
+    /// A map of nested configurations, one for each key found after the prefix set by
+    /// `#[config(map = "...")]`.
+    Map(String),
+}
+
+fn find_nested_kind(attr: &Attribute, compile_errors_stream: &mut TokenStream) -> NestedKind {
+    let mut prefix = None;
+    let mut indexed = None;
+    let mut map = None;
+
+    match attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated) {
+        Ok(options) => {
+            for option in options {
+                let slot = match option {
+                    Meta::NameValue(ref name_value) if name_value.path.is_ident("prefix") => {
+                        &mut prefix
+                    }
+                    Meta::NameValue(ref name_value) if name_value.path.is_ident("indexed") => {
+                        &mut indexed
+                    }
+                    Meta::NameValue(ref name_value) if name_value.path.is_ident("map") => &mut map,
+                    _ => {
+                        let error_stream: TokenStream = Error::new(
+                            option.span(),
+                            "Unknown config option, expected `prefix = \"...\"`, `indexed = \"...\"` or `map = \"...\"`",
+                        )
+                        .to_compile_error()
+                        .into();
+                        compile_errors_stream.extend(error_stream);
+                        continue;
+                    }
+                };
+
+                let value = &option.require_name_value().unwrap().value;
+
+                match value {
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(token),
+                        ..
+                    }) if !token.value().trim().is_empty() => {
+                        *slot = Some(token.value().trim().to_string());
+                    }
+                    _ => {
+                        let error_stream: TokenStream =
+                            Error::new(value.span(), "Prefix must be a non-empty string literal")
+                                .to_compile_error()
+                                .into();
+                        compile_errors_stream.extend(error_stream);
+                    }
+                }
+            }
+        }
+        Err(error) => compile_errors_stream.extend(TokenStream::from(error.to_compile_error())),
+    }
+
+    if [prefix.is_some(), indexed.is_some(), map.is_some()]
+        .into_iter()
+        .filter(|is_set| *is_set)
+        .count()
+        > 1
+    {
+        let error_stream: TokenStream = Error::new(
+            attr.span(),
+            "Only one of `prefix`, `indexed` and `map` can be set",
+        )
+        .to_compile_error()
+        .into();
+        compile_errors_stream.extend(error_stream);
+    }
+
+    match (indexed, map) {
+        (Some(indexed), _) => NestedKind::Indexed(indexed),
+        (None, Some(map)) => NestedKind::Map(map),
+        (None, None) => NestedKind::Single(prefix),
+    }
+}
+
+struct ContainerAttrs {
+    file_vars: bool,
+    rename_all: Option<RenameRule>,
+    tag: Option<String>,
+}
+
+fn find_container_attrs(
+    attrs: &[Attribute],
+    compile_errors_stream: &mut TokenStream,
+) -> ContainerAttrs {
+    let mut file_vars = false;
+    let mut rename_all = None;
+    let mut tag = None;
+
+    for attr in attrs {
+        if !attr.path().is_ident("config") || matches!(attr.meta, Meta::Path(_)) {
+            continue;
+        }
+
+        match attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated) {
+            Ok(options) => {
+                for option in options {
+                    match option {
+                        Meta::Path(ref path) if path.is_ident("file_vars") => {
+                            file_vars = true;
+                        }
+                        Meta::NameValue(ref name_value)
+                            if name_value.path.is_ident("rename_all") =>
+                        {
+                            match &name_value.value {
+                                Expr::Lit(ExprLit {
+                                    lit: Lit::Str(token),
+                                    ..
+                                }) => match RenameRule::from_name(&token.value()) {
+                                    Some(rule) => rename_all = Some(rule),
+                                    None => {
+                                        let error_stream: TokenStream = Error::new(
+                                            token.span(),
+                                            format!(
+                                                "Unknown rename rule, expected one of {}",
+                                                RenameRule::NAMES
+                                            ),
+                                        )
+                                        .to_compile_error()
+                                        .into();
+                                        compile_errors_stream.extend(error_stream);
+                                    }
+                                },
+                                other => {
+                                    let error_stream: TokenStream =
+                                        Error::new(other.span(), "Expecting a string literal")
+                                            .to_compile_error()
+                                            .into();
+                                    compile_errors_stream.extend(error_stream);
+                                }
+                            }
+                        }
+                        Meta::NameValue(ref name_value) if name_value.path.is_ident("tag") => {
+                            match &name_value.value {
+                                Expr::Lit(ExprLit {
+                                    lit: Lit::Str(token),
+                                    ..
+                                }) if !token.value().trim().is_empty() => {
+                                    tag = Some(token.value());
+                                }
+                                other => {
+                                    let error_stream: TokenStream = Error::new(
+                                        other.span(),
+                                        "Expecting a non-empty string literal",
+                                    )
+                                    .to_compile_error()
+                                    .into();
+                                    compile_errors_stream.extend(error_stream);
+                                }
+                            }
+                        }
+                        _ => {
+                            let error_stream: TokenStream = Error::new(
+                                option.span(),
+                                "Unknown config option, expected `file_vars`, `rename_all = \"...\"` or `tag = \"...\"`",
+                            )
+                            .to_compile_error()
+                            .into();
+                            compile_errors_stream.extend(error_stream);
+                        }
+                    }
+                }
+            }
+            Err(error) => compile_errors_stream.extend(TokenStream::from(error.to_compile_error())),
+        }
+    }
+
+    ContainerAttrs {
+        file_vars,
+        rename_all,
+        tag,
+    }
+}
+
+/// Collects the names a variant of a tagged enum is selected by: the aliases listed in its
+/// `#[variant("...")]` attributes, followed by its own name.
+fn find_variant_names(variant: &Variant, compile_errors_stream: &mut TokenStream) -> Vec<String> {
+    let mut names = Vec::new();
+
+    for attr in &variant.attrs {
+        if !attr.path().is_ident("variant") {
+            continue;
+        }
+
+        match attr.parse_args_with(Punctuated::<LitStr, Token![,]>::parse_terminated) {
+            Ok(aliases) if !aliases.is_empty() => {
+                for alias in aliases {
+                    if alias.value().trim().is_empty() {
+                        let error_stream: TokenStream =
+                            Error::new(alias.span(), "Variant name can't be empty")
+                                .to_compile_error()
+                                .into();
+                        compile_errors_stream.extend(error_stream);
+                    } else {
+                        names.push(alias.value());
+                    }
+                }
+            }
+            Ok(_) => {
+                let error_stream: TokenStream =
+                    Error::new(attr.span(), "Expecting at least one variant name")
+                        .to_compile_error()
+                        .into();
+                compile_errors_stream.extend(error_stream);
+            }
+            Err(error) => compile_errors_stream.extend(TokenStream::from(error.to_compile_error())),
+        }
+    }
+
+    names.push(variant.ident.unraw().to_string());
+
+    names
+}
+
+/// The value of a `#[default(...)]` attribute.
+enum DefaultValue {
+    /// An expression of the field's type.
+    Expr(TokenStream2),
+    /// A string literal, decoded by the field's decoder like a raw value.
+    Raw(LitStr),
 }
 
 fn find_default_attr(
     field: &Field,
     compile_error_stream: &mut TokenStream,
-) -> Option<TokenStream2> {
+) -> Option<(DefaultValue, String)> {
     let mut default_value = None;
 
     let field_type = &field.ty;
@@ -91,20 +460,19 @@ fn find_default_attr(
                     lit: Lit::Str(token),
                     ..
                 })) => {
-                    default_value = Some(quote! {
-                      {
-                        let tmp: #field_type = #token.to_string();
-                        tmp
-                      }
-                    });
+                    let text = token.value();
+                    default_value = Some((DefaultValue::Raw(token), text));
                 }
                 Ok(expr) => {
-                    default_value = Some(quote! {
-                      {
-                        let tmp: #field_type = #expr;
-                        tmp
-                      }
-                    });
+                    default_value = Some((
+                        DefaultValue::Expr(quote! {
+                          {
+                            let tmp: #field_type = #expr;
+                            tmp
+                          }
+                        }),
+                        quote! { #expr }.to_string(),
+                    ));
                 }
                 _ => {
                     let error_stream: TokenStream =
@@ -121,22 +489,52 @@ fn find_default_attr(
 }
 
 fn build_loading_expr(
-    field_name: &Option<Ident>,
+    field: &Field,
     field_idx: usize,
     env_attrs: Vec<String>,
-    default_value: Option<TokenStream2>,
-    field_type: &Type,
+    file_vars: Vec<String>,
+    credentials: Vec<String>,
+    decode_options: DecodeOptionsAttrs,
+    default_value: Option<DefaultValue>,
 ) -> TokenStream2 {
+    let field_type = &field.ty;
     let is_option = is_option(field_type);
-    let field_name = ident_opt_to_str(field_name);
+    let field_segment = field_segment(&field.ident, field_idx);
+    let field_name = ident_opt_to_str(&field.ident);
+    let decode_options_checks = decode_options.to_checks(field_type);
+    let decode_options = decode_options.to_tokens();
 
     let handle_missing_value = if is_option {
         quote! {
-          Ok(None)
+          {
+            ctx.record_missing(&[#(#env_attrs,)*], &[#(#file_vars,)*], #field_segment, tryphon::FieldProvenance::Unset);
+            Ok(None)
+          }
         }
     } else if let Some(default) = default_value {
+        let default = match default {
+            DefaultValue::Expr(expr) => quote! { Ok(#expr) },
+            DefaultValue::Raw(raw) => quote! {
+              <#field_type as tryphon::ConfigValueDecoder>::decode_with(#raw, &#decode_options).map_err(
+                |error| {
+                  tryphon::ConfigFieldError::Other {
+                    field_idx: #field_idx,
+                    field_name: #field_name,
+                    message: format!(
+                      "invalid default value '{}': {}",
+                      <#field_type as tryphon::ConfigValueDecoder>::redact_raw_with(#raw.to_string(), &#decode_options),
+                      error
+                    )
+                  }
+                })
+            },
+        };
+
         quote! {
-          Ok(#default)
+          {
+            ctx.record_missing(&[#(#env_attrs,)*], &[#(#file_vars,)*], #field_segment, tryphon::FieldProvenance::Default);
+            #default
+          }
         }
     } else {
         quote! {
@@ -144,48 +542,32 @@ fn build_loading_expr(
             tryphon::ConfigFieldError::MissingValue {
               field_name: #field_name,
               field_idx: #field_idx,
-              env_vars: vec![#(#env_attrs,)*].into_iter().map(String::from).collect()
+              env_vars: ctx.env_var_names(&[#(#env_attrs,)*], &[#(#file_vars,)*])
             }
           )
         }
     };
 
     if !env_attrs.is_empty() {
-        let mut iterator = env_attrs.iter();
-        let first_env_name = iterator
-            .next()
-            .expect("Expecting at least one loader")
-            .clone();
-        let mut loading_expr = quote! {
-          tryphon::read_env(#first_env_name).map(|v| (v, #first_env_name.to_string()))
-        };
-
-        for next_env_name in iterator {
-            loading_expr = quote! {
-              #loading_expr.or_else(|_| {  tryphon::read_env(#next_env_name).map(|v| (v, #next_env_name.to_string())) })
-            };
-        }
-
         quote! {
-          match #loading_expr {
-            Ok((raw, env_var_name)) => {
-              <#field_type as tryphon::ConfigValueDecoder>::decode(raw.clone()).map_err(
-                |message|{
+          {
+          #decode_options_checks
+          match ctx.lookup(&[#(#env_attrs,)*], &[#(#file_vars,)*], &[#(#credentials,)*], #field_segment) {
+            Ok(Some((tryphon::SourceValue { value: raw, path, .. }, env_var_name))) => {
+              <#field_type as tryphon::ConfigValueDecoder>::decode_with(&raw, &#decode_options).map_err(
+                move |error|{
                   tryphon::ConfigFieldError::ParsingError {
                     field_name: #field_name,
                     field_idx: #field_idx,
-                    raw: raw.clone(),
-                    message,
+                    raw: <#field_type as tryphon::ConfigValueDecoder>::redact_raw_with(raw, &#decode_options),
+                    error: tryphon::decode_error_with_path(error, path.as_deref()),
                     env_var_name
                   }
                 })
             },
-            Err(std::env::VarError::NotPresent) => #handle_missing_value,
-            Err(e @ std::env::VarError::NotUnicode(_)) => Err(tryphon::ConfigFieldError::Other {
-              message: e.to_string(),
-              field_name: #field_name,
-              field_idx: #field_idx,
-            })
+            Ok(None) => #handle_missing_value,
+            Err(error) => Err(error.into_field_error(#field_name, #field_idx))
+          }
           }
         }
     } else {
@@ -193,12 +575,47 @@ fn build_loading_expr(
     }
 }
 
-fn build_nested_config_expr(field: &Field, field_idx: usize) -> TokenStream2 {
+fn build_nested_config_expr(
+    field: &Field,
+    field_idx: usize,
+    nested_kind: &NestedKind,
+) -> TokenStream2 {
     let field_type = &field.ty;
+    let field_segment = field_segment(&field.ident, field_idx);
     let field_name = ident_opt_to_str(&field.ident);
 
+    let loading_expr = match nested_kind {
+        NestedKind::Single(prefix) => {
+            let nested_ctx = match prefix {
+                Some(prefix) => quote! { ctx.nested(#field_segment).with_prefix(#prefix) },
+                None => quote! { ctx.nested(#field_segment) },
+            };
+
+            match option_inner_type(field_type) {
+                Some(inner_type) => quote! {
+                  #nested_ctx.load_optional::<#inner_type>()
+                },
+                None => quote! {
+                  <#field_type as tryphon::Config>::load_fields(&#nested_ctx)
+                },
+            }
+        }
+        NestedKind::Indexed(prefix) => quote! {
+          {
+            let elements: Result<#field_type, tryphon::ConfigError> = ctx.load_indexed(#field_segment, #prefix);
+            elements
+          }
+        },
+        NestedKind::Map(prefix) => quote! {
+          {
+            let entries: Result<#field_type, tryphon::ConfigError> = ctx.load_map(#field_segment, #prefix);
+            entries
+          }
+        },
+    };
+
     quote! {
-      <#field_type as Config>::load().map_err(|error| tryphon::ConfigFieldError::Nested {
+      #loading_expr.map_err(|error| tryphon::ConfigFieldError::Nested {
         field_name: #field_name,
         error,
         field_idx: #field_idx,
@@ -206,9 +623,82 @@ fn build_nested_config_expr(field: &Field, field_idx: usize) -> TokenStream2 {
     }
 }
 
+fn build_describing_expr(
+    field_name: &Option<Ident>,
+    field_idx: usize,
+    env_attrs: &[String],
+    file_vars: &[String],
+    credentials: &[String],
+    default_value: Option<&String>,
+) -> TokenStream2 {
+    let field_segment = field_segment(field_name, field_idx);
+    let default_value = match default_value {
+        Some(default) => quote! { Some(#default.to_string()) },
+        None => quote! { None },
+    };
+
+    quote! {
+      fields.push(tryphon::FieldInfo {
+        path: path.with_segment(#field_segment),
+        env_vars: vec![#(#env_attrs,)*].into_iter().map(|name| format!("{}{}", prefix, name)).collect(),
+        file_vars: Vec::<&str>::from([#(#file_vars,)*]).into_iter().map(|name| format!("{}{}", prefix, name)).collect(),
+        credentials: vec![#(#credentials.to_string(),)*],
+        default: #default_value,
+      });
+    }
+}
+
+fn build_nested_describing_expr(
+    field: &Field,
+    field_idx: usize,
+    nested_kind: &NestedKind,
+) -> TokenStream2 {
+    let field_type = option_inner_type(&field.ty).unwrap_or(&field.ty);
+    let field_segment = field_segment(&field.ident, field_idx);
+
+    // Elements of indexed lists and maps are only known when loading, so they aren't described.
+    let NestedKind::Single(prefix) = nested_kind else {
+        return TokenStream2::new();
+    };
+
+    let prefix = prefix.clone().unwrap_or_default();
+
+    quote! {
+      <#field_type as tryphon::Config>::describe_fields(
+        &path.with_segment(#field_segment),
+        &format!("{}{}", prefix, #prefix),
+        fields,
+      );
+    }
+}
+
+/// Bounds every type parameter of `generics` mentioned in `ty` with `bound`.
+fn push_type_param_bounds(
+    generics: &Generics,
+    ty: &Type,
+    bound: TokenStream2,
+    bounds: &mut Vec<WherePredicate>,
+) {
+    for param in generics.type_params() {
+        let ident = &param.ident;
+
+        if type_mentions(ty, ident) {
+            let predicate: WherePredicate = parse_quote! { #ident: #bound };
+
+            if !bounds.contains(&predicate) {
+                bounds.push(predicate);
+            }
+        }
+    }
+}
+
 fn build_loading_for_struct(
     struct_name: TokenStream2,
     fields: Vec<&Field>,
+    rename_all: Option<RenameRule>,
+    generics: &Generics,
+    bounds: &mut Vec<WherePredicate>,
+    describing_exprs: &mut Vec<TokenStream2>,
     compile_errors_stream: &mut TokenStream,
 ) -> TokenStream2 {
     let mut loading_exprs = Vec::new();
@@ -216,20 +706,50 @@ fn build_loading_for_struct(
     let struct_type = StructType::from_fields(&fields);
 
     for (field_idx, field) in fields.iter().enumerate() {
-        let field_type = &field.ty;
         let default_attr = find_default_attr(field, compile_errors_stream);
-        let (env_attrs, is_nested_config) = find_attrs(field, compile_errors_stream);
-        if !env_attrs.is_empty() {
+        let FieldAttrs {
+            env_vars,
+            file_vars,
+            credentials,
+            decode_options,
+            is_nested_config,
+            nested_kind,
+        } = find_attrs(field, rename_all, compile_errors_stream);
+        if !env_vars.is_empty() {
+            push_type_param_bounds(
+                generics,
+                &field.ty,
+                quote! { tryphon::ConfigValueDecoder },
+                bounds,
+            );
+            describing_exprs.push(build_describing_expr(
+                &field.ident,
+                field_idx,
+                &env_vars,
+                &file_vars,
+                &credentials,
+                default_attr.as_ref().map(|(_, text)| text),
+            ));
             loading_exprs.push((
                 field.ident.clone(),
                 field_idx,
-                build_loading_expr(&field.ident, field_idx, env_attrs, default_attr, field_type),
+                build_loading_expr(
+                    field,
+                    field_idx,
+                    env_vars,
+                    file_vars,
+                    credentials,
+                    decode_options,
+                    default_attr.map(|(value, _)| value),
+                ),
             ));
         } else if is_nested_config {
+            push_type_param_bounds(generics, &field.ty, quote! { tryphon::Config }, bounds);
+            describing_exprs.push(build_nested_describing_expr(field, field_idx, &nested_kind));
             loading_exprs.push((
                 field.ident.clone(),
                 field_idx,
-                build_nested_config_expr(field, field_idx),
+                build_nested_config_expr(field, field_idx, &nested_kind),
             ));
         }
     }
@@ -308,8 +828,9 @@ fn build_loading_for_struct(
 /// Derives the `Config` trait for a struct or enum to enable loading configuration from environment variables.
 ///
 /// This macro automatically implements the `Config` trait, generating code that reads
-/// environment variables and constructs instances of your type with proper error handling and validation.
-#[proc_macro_derive(Config, attributes(env, default, config))]
+/// values from a `Source` (the process environment by default) and constructs instances of
+/// your type with proper error handling and validation.
+#[proc_macro_derive(Config, attributes(env, default, config, credential, variant))]
 pub fn derive_config(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
 
@@ -317,13 +838,40 @@ pub fn derive_config(input: TokenStream) -> TokenStream {
 
     let mut compile_errors_stream = TokenStream::new();
 
+    let ContainerAttrs {
+        file_vars,
+        rename_all,
+        tag,
+    } = find_container_attrs(&ast.attrs, &mut compile_errors_stream);
+
+    if tag.is_some() && !matches!(ast.data, Data::Enum(_)) {
+        let error_stream: TokenStream =
+            Error::new(Span::call_site(), "`tag` can only be set on enums")
+                .to_compile_error()
+                .into();
+        compile_errors_stream.extend(error_stream);
+    }
+
+    let mut describing_exprs = Vec::new();
+    let mut bounds = Vec::new();
+
     let building_expr = match ast.data {
         Data::Struct(syn::DataStruct { ref fields, .. }) => {
             let name = quote! { #struct_name };
 
-            build_loading_for_struct(name, fields.iter().collect(), &mut compile_errors_stream)
+            build_loading_for_struct(
+                name,
+                fields.iter().collect(),
+                rename_all,
+                &ast.generics,
+                &mut bounds,
+                &mut describing_exprs,
+                &mut compile_errors_stream,
+            )
         }
         Data::Enum(syn::DataEnum { ref variants, .. }) => {
+            let mut variant_names: Vec<Vec<String>> = Vec::new();
+
             let building_exprs = variants
                 .iter()
                 .map(|v| {
@@ -334,37 +882,154 @@ pub fn derive_config(input: TokenStream) -> TokenStream {
 
                     let name = quote! { #path };
 
+                    let names = find_variant_names(v, &mut compile_errors_stream);
+
+                    if tag.is_none() && names.len() > 1 {
+                        let error_stream: TokenStream = Error::new(
+                            v.span(),
+                            "`#[variant]` requires `#[config(tag = \"...\")]` on the enum",
+                        )
+                        .to_compile_error()
+                        .into();
+                        compile_errors_stream.extend(error_stream);
+                    }
+
+                    for name in &names {
+                        let is_duplicate = variant_names
+                            .iter()
+                            .flatten()
+                            .any(|other| other.to_lowercase() == name.to_lowercase());
+
+                        if tag.is_some() && is_duplicate {
+                            let error_stream: TokenStream = Error::new(
+                                v.span(),
+                                format!("Variant name \"{name}\" is already used"),
+                            )
+                            .to_compile_error()
+                            .into();
+                            compile_errors_stream.extend(error_stream);
+                        }
+                    }
+
+                    variant_names.push(names);
+
                     build_loading_for_struct(
                         name,
                         v.fields.iter().collect(),
+                        rename_all,
+                        &ast.generics,
+                        &mut bounds,
+                        &mut describing_exprs,
                         &mut compile_errors_stream,
                     )
                 })
                 .collect::<Vec<_>>();
 
-            let mut iter = building_exprs.iter();
-            let mut acc = iter.next().expect("Expecting at least one element").clone();
+            match &tag {
+                Some(tag) => {
+                    let tag_segment = Some(Ident::new("tag", Span::call_site()));
+                    describing_exprs.insert(
+                        0,
+                        build_describing_expr(
+                            &tag_segment,
+                            0,
+                            std::slice::from_ref(tag),
+                            &[],
+                            &[],
+                            None,
+                        ),
+                    );
+
+                    let arms = building_exprs.iter().enumerate().map(|(idx, expr)| {
+                        quote! {
+                          #idx => #expr
+                        }
+                    });
 
-            for next in iter {
-                acc = quote! {
-                  #acc.or_else(|_| { #next })
-                };
-            }
+                    let names = variant_names.iter().map(|names| {
+                        quote! { &[#(#names,)*] }
+                    });
+
+                    quote! {
+                      match ctx.select_variant("tag", #tag, &[#(#names,)*])? {
+                        #(#arms ,)*
+                        _ => unreachable!("Unknown variant index"),
+                      }
+                    }
+                }
+                None => {
+                    let attempts = building_exprs.iter().zip(variants).map(|(expr, v)| {
+                        let variant_name = v.ident.unraw().to_string();
 
-            acc
+                        quote! {
+                          match #expr {
+                            Ok(config) => return Ok(config),
+                            Err(error) => {
+                              ctx.rollback_report(report_checkpoint);
+                              variant_errors.push((#variant_name.to_string(), error));
+                            }
+                          }
+                        }
+                    });
+
+                    quote! {
+                      let report_checkpoint = ctx.report_checkpoint();
+                      let mut variant_errors = Vec::new();
+
+                      #(#attempts)*
+
+                      Err(tryphon::ConfigError {
+                        field_errors: vec![tryphon::ConfigFieldError::NoVariantLoaded { variant_errors }],
+                      })
+                    }
+                }
+            }
         }
         Data::Union(_) => {
             Error::new(Span::call_site(), "Union type is not supported!").to_compile_error()
         }
     };
 
+    let file_vars_ctx = if file_vars {
+        quote! {
+          let ctx = &ctx.with_file_vars();
+        }
+    } else {
+        TokenStream2::new()
+    };
+
+    // With `#[config(file_vars)]`, every name of the fields, nested ones included, has a
+    // `_FILE` variant.
+    let describing_exprs = if file_vars {
+        quote! {
+          let first_field = fields.len();
+          #(#describing_exprs)*
+          for field in &mut fields[first_field..] {
+            field.file_vars = field.env_vars.clone();
+          }
+        }
+    } else {
+        quote! { #(#describing_exprs)* }
+    };
+
+    let mut generics = ast.generics.clone();
+    generics.make_where_clause().predicates.extend(bounds);
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
     if compile_errors_stream.is_empty() {
         quote! {
-          impl tryphon::Config for #struct_name {
+          impl #impl_generics tryphon::Config for #struct_name #type_generics #where_clause {
 
-              fn load() -> Result<Self, tryphon::ConfigError> {
+              #[allow(unused_variables)]
+              fn load_fields(ctx: &tryphon::LoadContext<'_>) -> Result<Self, tryphon::ConfigError> {
+                #file_vars_ctx
                 #building_expr
               }
+
+              #[allow(unused_variables)]
+              fn describe_fields(path: &tryphon::FieldPath, prefix: &str, fields: &mut Vec<tryphon::FieldInfo>) {
+                #describing_exprs
+              }
           }
         }
         .into()
@@ -389,14 +1054,17 @@ pub fn derive_config_value_decoder(input: TokenStream) -> TokenStream {
     match ast.data {
         Data::Enum(syn::DataEnum { ref variants, .. }) => {
             let enum_name = ast.ident;
+            let (impl_generics, type_generics, where_clause) = ast.generics.split_for_impl();
 
             let mut cases = vec![];
+            let mut names = vec![];
 
             for variant in variants {
                 if variant.fields.is_empty() {
                     let variant_name = &variant.ident.to_string();
 
                     let variant_name_lowercased = variant_name.to_lowercase();
+                    names.push(variant_name_lowercased.clone());
 
                     let full_variant_name = format!("{enum_name}::{variant_name}");
 
@@ -415,14 +1083,15 @@ pub fn derive_config_value_decoder(input: TokenStream) -> TokenStream {
                 }
             }
 
+            let expected = format!("one of: {}", names.join(", "));
+
             quote! {
-              impl tryphon::ConfigValueDecoder for #enum_name {
-                fn decode(raw: String) -> Result<Self, String> {
+              impl #impl_generics tryphon::ConfigValueDecoder for #enum_name #type_generics #where_clause {
+                fn decode_with(raw: &str, _options: &tryphon::DecodeOptions) -> Result<Self, tryphon::DecodeError> {
                     match raw.to_lowercase().as_str() {
                       #(#cases ,)*
-                      _ => Err(format!("Invalid log level: {}", raw)),
+                      _ => Err(tryphon::DecodeError::new(format!("invalid variant '{}'", raw)).with_expected(#expected)),
                     }
-
                 }
               }
             }
@@ -436,6 +1105,18 @@ pub fn derive_config_value_decoder(input: TokenStream) -> TokenStream {
 
                 let field_type = &underlying_field.ty;
 
+                let mut bounds = Vec::new();
+                push_type_param_bounds(
+                    &ast.generics,
+                    field_type,
+                    quote! { tryphon::ConfigValueDecoder },
+                    &mut bounds,
+                );
+
+                let mut generics = ast.generics.clone();
+                generics.make_where_clause().predicates.extend(bounds);
+                let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
                 let constructor = match &underlying_field.ident {
                     Some(field_name) => quote! {
                        #struct_name{#field_name: decoded}
@@ -448,10 +1129,22 @@ pub fn derive_config_value_decoder(input: TokenStream) -> TokenStream {
                 };
 
                 quote! {
-              impl tryphon::ConfigValueDecoder for #struct_name {
+              impl #impl_generics tryphon::ConfigValueDecoder for #struct_name #type_generics #where_clause {
                 fn decode(raw: String) -> Result<Self, String> {
                   <#field_type as tryphon::ConfigValueDecoder>::decode(raw).map(|decoded| #constructor)
                 }
+
+                fn decode_with(raw: &str, options: &tryphon::DecodeOptions) -> Result<Self, tryphon::DecodeError> {
+                  <#field_type as tryphon::ConfigValueDecoder>::decode_with(raw, options).map(|decoded| #constructor)
+                }
+
+                fn redact_raw(raw: String) -> String {
+                  <#field_type as tryphon::ConfigValueDecoder>::redact_raw(raw)
+                }
+
+                fn redact_raw_with(raw: String, options: &tryphon::DecodeOptions) -> String {
+                  <#field_type as tryphon::ConfigValueDecoder>::redact_raw_with(raw, options)
+                }
               }
             }.into()
             } else {
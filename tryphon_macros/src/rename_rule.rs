@@ -0,0 +1,124 @@
+/// A rule for deriving an environment variable name from a field name, as accepted by
+/// `#[config(rename_all = "...")]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RenameRule {
+    Lower,
+    Upper,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+    Camel,
+    Pascal,
+}
+
+impl RenameRule {
+    pub(crate) const NAMES: &'static str = "\"lowercase\", \"UPPERCASE\", \"snake_case\", \"SCREAMING_SNAKE_CASE\", \"kebab-case\", \"SCREAMING-KEBAB-CASE\", \"camelCase\" or \"PascalCase\"";
+
+    pub(crate) fn from_name(name: &str) -> Option<RenameRule> {
+        match name {
+            "lowercase" => Some(RenameRule::Lower),
+            "UPPERCASE" => Some(RenameRule::Upper),
+            "snake_case" => Some(RenameRule::Snake),
+            "SCREAMING_SNAKE_CASE" => Some(RenameRule::ScreamingSnake),
+            "kebab-case" => Some(RenameRule::Kebab),
+            "SCREAMING-KEBAB-CASE" => Some(RenameRule::ScreamingKebab),
+            "camelCase" => Some(RenameRule::Camel),
+            "PascalCase" => Some(RenameRule::Pascal),
+            _ => None,
+        }
+    }
+
+    /// Applies the rule to a snake_case field name.
+    pub(crate) fn apply(&self, field_name: &str) -> String {
+        let words = field_name
+            .split('_')
+            .filter(|word| !word.is_empty())
+            .map(|word| word.to_lowercase())
+            .collect::<Vec<_>>();
+
+        match self {
+            RenameRule::Lower => field_name.to_lowercase(),
+            RenameRule::Upper => field_name.to_uppercase(),
+            RenameRule::Snake => words.join("_"),
+            RenameRule::ScreamingSnake => words.join("_").to_uppercase(),
+            RenameRule::Kebab => words.join("-"),
+            RenameRule::ScreamingKebab => words.join("-").to_uppercase(),
+            RenameRule::Camel => words
+                .iter()
+                .enumerate()
+                .map(|(idx, word)| {
+                    if idx == 0 {
+                        word.clone()
+                    } else {
+                        capitalize(word)
+                    }
+                })
+                .collect(),
+            RenameRule::Pascal => words.iter().map(|word| capitalize(word)).collect(),
+        }
+    }
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RenameRule;
+
+    fn apply(rule: &str, field_name: &str) -> String {
+        RenameRule::from_name(rule).unwrap().apply(field_name)
+    }
+
+    #[test]
+    fn test_lowercase() {
+        assert_eq!(apply("lowercase", "database_url"), "database_url");
+    }
+
+    #[test]
+    fn test_uppercase() {
+        assert_eq!(apply("UPPERCASE", "database_url"), "DATABASE_URL");
+    }
+
+    #[test]
+    fn test_snake_case() {
+        assert_eq!(apply("snake_case", "database__url_"), "database_url");
+    }
+
+    #[test]
+    fn test_screaming_snake_case() {
+        assert_eq!(
+            apply("SCREAMING_SNAKE_CASE", "database_url"),
+            "DATABASE_URL"
+        );
+    }
+
+    #[test]
+    fn test_kebab_case() {
+        assert_eq!(apply("kebab-case", "database_url"), "database-url");
+    }
+
+    #[test]
+    fn test_screaming_kebab_case() {
+        assert_eq!(
+            apply("SCREAMING-KEBAB-CASE", "database_url"),
+            "DATABASE-URL"
+        );
+    }
+
+    #[test]
+    fn test_camel_case() {
+        assert_eq!(apply("camelCase", "max_pool_size"), "maxPoolSize");
+    }
+
+    #[test]
+    fn test_pascal_case() {
+        assert_eq!(apply("PascalCase", "max_pool_size"), "MaxPoolSize");
+    }
+}
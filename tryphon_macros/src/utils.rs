@@ -1,7 +1,8 @@
-use proc_macro2::Ident;
-use quote::quote;
+use proc_macro2::{Ident, TokenTree};
+use quote::{ToTokens, quote};
 use syn::__private::TokenStream2;
-use syn::{Type, TypePath};
+use syn::ext::IdentExt;
+use syn::{GenericArgument, PathArguments, Type, TypePath};
 
 pub(crate) fn is_option(ty: &Type) -> bool {
     if let Type::Path(TypePath { path, .. }) = ty {
@@ -16,6 +17,27 @@ pub(crate) fn is_option(ty: &Type) -> bool {
     }
 }
 
+/// Returns `T` if `ty` is `Option<T>`.
+pub(crate) fn option_inner_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(TypePath { path, .. }) = ty else {
+        return None;
+    };
+
+    let segment = path.segments.last()?;
+
+    if segment.ident != "Option" {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) => match arguments.args.first()? {
+            GenericArgument::Type(inner_type) => Some(inner_type),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 pub(crate) fn ident_opt_to_str(field_name: &Option<Ident>) -> TokenStream2 {
     match field_name {
         Some(ident) => {
@@ -25,3 +47,23 @@ pub(crate) fn ident_opt_to_str(field_name: &Option<Ident>) -> TokenStream2 {
         _ => quote! { None },
     }
 }
+
+pub(crate) fn field_segment(field_name: &Option<Ident>, field_idx: usize) -> String {
+    match field_name {
+        Some(ident) => ident.unraw().to_string(),
+        None => field_idx.to_string(),
+    }
+}
+
+/// Checks if `ty` mentions the identifier `ident`, e.g. a type parameter.
+pub(crate) fn type_mentions(ty: &Type, ident: &Ident) -> bool {
+    fn tokens_mention(tokens: TokenStream2, ident: &Ident) -> bool {
+        tokens.into_iter().any(|token| match token {
+            TokenTree::Ident(token) => token == *ident,
+            TokenTree::Group(group) => tokens_mention(group.stream(), ident),
+            _ => false,
+        })
+    }
+
+    tokens_mention(ty.to_token_stream(), ident)
+}
@@ -0,0 +1,16 @@
+use std::time::Duration;
+use tryphon::Config;
+
+#[derive(Config)]
+struct CacheConfig {
+    #[env("CACHE_SIZE", byte_size)]
+    size: i64,
+
+    #[env("CACHE_TTL", byte_size)]
+    ttl: Option<Duration>,
+
+    #[env("CACHE_LIMITS", byte_size)]
+    limits: Vec<u64>,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use tryphon::ConfigValueDecoder;
+
+struct Level(u8);
+
+impl ConfigValueDecoder for Level {}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use tryphon::Config;
+
+#[derive(Config)]
+struct BadConfig(#[env] String);
+
+fn main() {}
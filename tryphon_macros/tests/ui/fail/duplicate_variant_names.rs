@@ -0,0 +1,18 @@
+use tryphon::Config;
+
+#[derive(Config)]
+#[config(tag = "MESSAGING_BACKEND")]
+enum MessagingConfig {
+    #[variant("kafka")]
+    Kafka {
+        #[env("KAFKA_BROKER")]
+        broker: String,
+    },
+    #[variant("KAFKA")]
+    Redpanda {
+        #[env("REDPANDA_BROKER")]
+        broker: String,
+    },
+}
+
+fn main() {}
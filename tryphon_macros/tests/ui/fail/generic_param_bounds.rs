@@ -0,0 +1,13 @@
+use tryphon::Config;
+
+#[derive(Config)]
+struct ServiceConfig<B> {
+    #[config]
+    backend: B,
+}
+
+struct NotAConfig;
+
+fn main() {
+    let _ = ServiceConfig::<NotAConfig>::load();
+}
@@ -0,0 +1,15 @@
+use tryphon::Config;
+
+#[derive(Config)]
+struct UpstreamConfig {
+    #[env("HOST")]
+    host: String,
+}
+
+#[derive(Config)]
+struct BadConfig {
+    #[config(indexed = "UPSTREAM_", prefix = "PROXY_")]
+    upstreams: Vec<UpstreamConfig>,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use tryphon::Config;
+
+#[derive(Config)]
+struct BadConfig {
+    #[env("HOSTS", separator = "")]
+    hosts: Vec<String>,
+
+    #[env("PORTS", delimiter = ";")]
+    ports: Vec<u16>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use tryphon::Config;
+
+#[derive(Config)]
+#[config(rename_all = "SHOUTING")]
+struct BadConfig {
+    host: String,
+}
+
+fn main() {}
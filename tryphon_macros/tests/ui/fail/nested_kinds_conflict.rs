@@ -0,0 +1,16 @@
+use tryphon::Config;
+use std::collections::HashMap;
+
+#[derive(Config)]
+struct DbConfig {
+    #[env("HOST")]
+    host: String,
+}
+
+#[derive(Config)]
+struct BadConfig {
+    #[config(map = "DB_", indexed = "DB_")]
+    databases: HashMap<String, DbConfig>,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use tryphon::Config;
+
+#[derive(Config)]
+#[config(tag = "BACKEND")]
+struct AppConfig {
+    #[env("NAME")]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use tryphon::Config;
+
+#[derive(Config)]
+enum MessagingConfig {
+    #[variant("kafka")]
+    Kafka {
+        #[env("KAFKA_BROKER")]
+        broker: String,
+    },
+    Mock {
+        #[env("MOCK_BROKER")]
+        broker: String,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+use tryphon::Config;
+
+#[derive(Config)]
+struct ServerConfig {
+    #[env]
+    host: String,
+
+    #[env(file)]
+    password: String,
+}
+
+#[derive(Config)]
+#[config(rename_all = "kebab-case")]
+struct ClientConfig {
+    request_timeout: u64,
+
+    #[env("CLIENT_RETRIES")]
+    retries: u8,
+
+    #[config]
+    server: ServerConfig,
+}
+
+fn main() {}
@@ -0,0 +1,75 @@
+use tryphon::{Config, ConfigValueDecoder, DecodeError, DecodeOptions};
+
+#[derive(Config)]
+struct PostgresConfig {
+    #[env("POSTGRES_URL")]
+    url: String,
+}
+
+#[derive(Config)]
+struct ServiceConfig<B> {
+    #[env("SERVICE_NAME")]
+    name: String,
+
+    #[config(prefix = "SERVICE_")]
+    backend: B,
+}
+
+#[derive(Config)]
+struct FallbackConfig<B, T: Default>
+where
+    B: Send,
+{
+    #[config]
+    backend: Option<B>,
+
+    #[env("FALLBACK_TIMEOUT")]
+    #[default(T::default())]
+    timeout: T,
+
+    #[env("FALLBACK_TAGS")]
+    tags: Vec<T>,
+}
+
+#[derive(Config)]
+enum BackendConfig<P, Q> {
+    Primary {
+        #[config]
+        primary: P,
+    },
+    Secondary {
+        #[config(prefix = "SECONDARY_")]
+        secondary: Q,
+    },
+}
+
+struct Label<'a>(&'a str);
+
+impl<'a> ConfigValueDecoder for Label<'a> {
+    fn decode_with(raw: &str, _options: &DecodeOptions) -> Result<Self, DecodeError> {
+        Ok(Label(raw.to_string().leak()))
+    }
+}
+
+#[derive(Config)]
+struct LabeledConfig<'a> {
+    #[env("LABEL")]
+    label: Label<'a>,
+}
+
+#[derive(ConfigValueDecoder)]
+struct Wrapper<T>(T);
+
+#[derive(ConfigValueDecoder)]
+struct Named<'a> {
+    label: Label<'a>,
+}
+
+fn main() {
+    let _ = ServiceConfig::<PostgresConfig>::load();
+    let _ = FallbackConfig::<PostgresConfig, u64>::load();
+    let _ = BackendConfig::<PostgresConfig, PostgresConfig>::load();
+    let _ = LabeledConfig::load();
+    let _ = <Wrapper<u16> as ConfigValueDecoder>::decode("8080".to_string());
+    let _ = <Named as ConfigValueDecoder>::decode("primary".to_string());
+}
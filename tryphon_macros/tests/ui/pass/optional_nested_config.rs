@@ -7,9 +7,9 @@ struct NestedConfig {
 }
 
 #[derive(Config)]
-struct BadConfig {
+struct OptionalConfig {
     #[config]
-    nested: Option<NestedConfig>,  // Invalid
+    nested: Option<NestedConfig>,
 }
 
 fn main() {}
@@ -0,0 +1,14 @@
+use tryphon::Config;
+
+#[derive(Config)]
+#[config(tag = "MESSAGING_BACKEND")]
+enum MessagingConfig {
+    #[variant("kafka", "confluent")]
+    Kafka {
+        #[env("KAFKA_BROKER")]
+        broker: String,
+    },
+    Mock,
+}
+
+fn main() {}